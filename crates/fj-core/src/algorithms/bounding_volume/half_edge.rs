@@ -45,7 +45,7 @@ impl super::BoundingVolume<2>
                         path.point_from_path_coords(point_curve)
                     });
 
-                Some(Aabb::<2>::from_points(points))
+                Aabb::<2>::from_points(points)
             }
         }
     }
@@ -53,7 +53,8 @@ impl LineSegmentIntersection {
         let t = n_dot_origin / n_dot_direction;
 
         let point_is_on_segment = Aabb::<2>::from_points(segment.points)
-            .contains(line.point_from_line_coords([t]));
+            .expect("Segment has two points")
+            .contains_point(line.point_from_line_coords([t]));
         if !point_is_on_segment {
             return None;
         }
@@ -77,11 +77,13 @@ pub fn convert_from_curve_point<const D: usize>(
 }
 
 /// # Generate a 2D axis-aligned bounding box for a curve in a given range
+///
+/// Returns `None`, if the polyline generated for the curve has no points.
 pub fn surface_aabb_from_bounded_curve(
     curve: &dyn GenPolyline<2>,
     boundary: impl Into<CurveBoundary<Point<1>>>,
     tolerance: impl Into<Tolerance>,
-) -> Aabb<2> {
+) -> Option<Aabb<2>> {
     let boundary = boundary.into();
     let tolerance = tolerance.into();
 
@@ -10,13 +10,13 @@
 
 use std::{
     fs::File,
-    io::{Seek, Write},
+    io::{self, Seek, Write},
     path::Path,
 };
 
 use thiserror::Error;
 
-use fj_interop::vertices_to_indexed_vertices;
+use fj_interop::{TriMesh, vertices_to_indexed_vertices};
 use fj_math::Triangle;
 
 /// # Export the provided mesh to the file at the given path
@@ -132,6 +132,49 @@ pub fn export_stl(
     Ok(())
 }
 
+/// # Write the provided mesh to the provided writer as binary STL
+///
+/// Writes an all-zero 80-byte header, followed by the triangle count and, for
+/// each triangle, its normal and its three vertices. All numbers are written in
+/// little-endian byte order, as the format requires.
+///
+/// Degenerate triangles don't have a normal. They are written with a zero
+/// normal instead, which STL readers accept.
+///
+/// Unlike [`export_stl`], this writes the binary layout directly, without
+/// going through an intermediate representation.
+pub fn write_stl(mesh: &TriMesh, mut write: impl Write) -> io::Result<()> {
+    let num_triangles: u32 = mesh.triangles.len().try_into().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "maximum triangle count exceeded",
+        )
+    })?;
+
+    write.write_all(&[0u8; 80])?;
+    write.write_all(&num_triangles.to_le_bytes())?;
+
+    for triangle in mesh.all_triangles() {
+        let [a, b, c] = triangle.points;
+        let normal = (b - a).cross(&(c - a)).normalize_checked();
+        let normal = normal.unwrap_or_default();
+
+        for s in normal.components {
+            write.write_all(&s.into_f32().to_le_bytes())?;
+        }
+        for point in triangle.points {
+            for s in point.coords.components {
+                write.write_all(&s.into_f32().to_le_bytes())?;
+            }
+        }
+
+        // attribute byte count
+        write.write_all(&0u16.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
 /// # Export the provided mesh to the provided writer in the OBJ format
 pub fn export_obj(
     triangles: impl IntoIterator<Item = Triangle<3>>,
@@ -210,3 +253,69 @@ pub enum Error {
     #[error("obj error whilst exporting to OBJ file")]
     OBJ,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::{Color, MeshTriangle, TriMesh};
+    use fj_math::Triangle;
+
+    #[test]
+    fn write_stl() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+
+        let mut mesh = TriMesh::new();
+        mesh.triangles.push(MeshTriangle {
+            inner: triangle,
+            is_internal: false,
+            color: Color::default(),
+        });
+
+        let mut buf = Vec::new();
+        super::write_stl(&mesh, &mut buf).unwrap();
+
+        assert_eq!(buf.len(), 84 + 50);
+        assert_eq!(buf[..80], [0u8; 80]);
+        assert_eq!(buf[80..84], 1u32.to_le_bytes());
+
+        let floats = buf[84..132]
+            .chunks(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            floats,
+            [
+                0., 0., 1., // normal
+                0., 0., 0., // v1
+                1., 0., 0., // v2
+                0., 1., 0., // v3
+            ]
+        );
+
+        assert_eq!(buf[132..134], [0, 0]);
+    }
+
+    #[test]
+    fn write_stl_degenerate_triangle() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [1., 1., 1.], [2., 2., 2.]]);
+
+        let mut mesh = TriMesh::new();
+        mesh.triangles.push(MeshTriangle {
+            inner: triangle,
+            is_internal: false,
+            color: Color::default(),
+        });
+
+        let mut buf = Vec::new();
+        super::write_stl(&mesh, &mut buf).unwrap();
+
+        assert_eq!(buf.len(), 84 + 50);
+
+        let normal = buf[84..96]
+            .chunks(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(normal, [0., 0., 0.]);
+    }
+}
@@ -12,6 +12,9 @@ use fj_math::Scalar;
 /// The `Tolerance` type enforces that the tolerance value is always larger than
 /// zero, which is an attribute that the approximation code relies on.
 ///
+/// Tolerances are ordered by their value. If multiple tolerances apply to an
+/// approximation, [`Ord::min`] picks the strictest one.
+///
 /// ## Failing [`From`]/[`Into`] implementation
 ///
 /// The [`From`]/[`Into`] implementations of tolerance are fallible, which goes
@@ -60,3 +63,34 @@ where
 #[derive(Debug, thiserror::Error)]
 #[error("Invalid tolerance ({0}); must be above zero")]
 pub struct InvalidTolerance(Scalar);
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use super::Tolerance;
+
+    #[test]
+    fn comparison() {
+        let fine = Tolerance::from(0.001);
+        let coarse = Tolerance::from(0.1);
+
+        assert!(fine < coarse);
+        assert_eq!(fine.inner(), Scalar::from(0.001));
+    }
+
+    #[test]
+    fn min() {
+        let fine = Tolerance::from(0.001);
+        let coarse = Tolerance::from(0.1);
+
+        assert_eq!(fine.min(coarse), fine);
+        assert_eq!(coarse.min(fine), fine);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Tolerance::from_scalar(0.).is_err());
+        assert!(Tolerance::from_scalar(-1.).is_err());
+    }
+}
@@ -59,12 +59,15 @@ impl TriMesh {
     }
 
     /// # Compute the axis-aligned bounding box of this mesh
+    ///
+    /// Returns an empty AABB at the origin, if the mesh has no triangles.
     pub fn aabb(&self) -> Aabb<3> {
         Aabb::<3>::from_points(
             self.triangles
                 .iter()
                 .flat_map(|triangle| triangle.inner.points),
         )
+        .unwrap_or_default()
     }
 }
 
@@ -1,7 +1,7 @@
 use parry2d_f64::bounding_volume::BoundingVolume as _;
 use parry3d_f64::bounding_volume::BoundingVolume as _;
 
-use super::{Point, Vector};
+use super::{Point, Scalar, Vector};
 
 /// An axis-aligned bounding box (AABB)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -15,8 +15,46 @@ pub struct Aabb<const D: usize> {
 }
 
 impl<const D: usize> Aabb<D> {
+    /// Construct an AABB from a list of points
+    ///
+    /// The resulting AABB is the smallest one that contains all the points.
+    /// Returns `None`, if the list is empty.
+    pub fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Option<Self> {
+        let mut points = points.into_iter().map(Into::into);
+
+        let first = points.next()?;
+        let aabb = points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |aabb, point| {
+                aabb.union(&Self {
+                    min: point,
+                    max: point,
+                })
+            },
+        );
+
+        Some(aabb)
+    }
+
+    /// Compute the center point of the AABB
+    pub fn center(&self) -> Point<D> {
+        self.min + self.size() / 2.
+    }
+
+    /// Compute the size of the AABB
+    pub fn size(&self) -> Vector<D> {
+        self.max - self.min
+    }
+
     /// Determine whether the AABB contains a given point
-    pub fn contains(&self, point: impl Into<Point<D>>) -> bool {
+    ///
+    /// Points on the boundary of the AABB are considered to be contained.
+    pub fn contains_point(&self, point: impl Into<Point<D>>) -> bool {
         let point = point.into();
 
         let min = self
@@ -45,19 +83,72 @@ impl<const D: usize> Aabb<D> {
 
         true
     }
-}
 
-impl Aabb<2> {
-    /// Construct a 2-dimensional AABB from a list of points
+    /// Determine whether the AABB contains a given point
     ///
-    /// The resulting AABB will contain all the points.
-    pub fn from_points(
-        points: impl IntoIterator<Item = impl Into<Point<2>>>,
-    ) -> Self {
-        let points = points.into_iter().map(|point| point.into().to_na());
-        parry2d_f64::bounding_volume::Aabb::from_points(points).into()
+    /// This is the former name of [`Aabb::contains_point`].
+    #[deprecated = "use `Aabb::contains_point` instead"]
+    pub fn contains(&self, point: impl Into<Point<D>>) -> bool {
+        self.contains_point(point)
+    }
+
+    /// Determine whether the AABB fully contains another AABB
+    ///
+    /// AABBs that touch the boundary of this one from the inside are
+    /// considered to be contained.
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Compute the smallest AABB that contains both this AABB and another
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.component_min(other.min),
+            max: self.max.component_max(other.max),
+        }
     }
 
+    /// Compute the AABB in which this AABB and another overlap
+    ///
+    /// Returns `None`, if the AABBs are disjoint. If they only touch, the
+    /// result is an AABB that has zero size along at least one axis.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self {
+            min: self.min.component_max(other.min),
+            max: self.max.component_min(other.max),
+        };
+
+        let is_disjoint = intersection
+            .min
+            .coords
+            .components
+            .into_iter()
+            .zip(intersection.max.coords.components)
+            .any(|(min, max)| min > max);
+
+        if is_disjoint {
+            return None;
+        }
+
+        Some(intersection)
+    }
+
+    /// Grow the AABB by the given margin along every axis
+    ///
+    /// A negative margin shrinks the AABB. If it is shrunk by more than half
+    /// its size along an axis, the result is inverted along that axis, meaning
+    /// `min` is larger than `max`.
+    pub fn expand(&self, margin: impl Into<Scalar>) -> Self {
+        let margin = Vector::from([margin.into(); D]);
+
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+impl Aabb<2> {
     /// Construct a 2-dimensional AABB from a Parry AABB
     pub fn from_parry(aabb: parry2d_f64::bounding_volume::Aabb) -> Self {
         Self {
@@ -74,6 +165,21 @@ impl Aabb<2> {
         }
     }
 
+    /// Access the corners of the AABB
+    ///
+    /// The corners are returned in counter-clockwise order, starting with
+    /// `min`.
+    pub fn corners_2d(&self) -> [Point<2>; 4] {
+        let [min, max] = [self.min, self.max];
+
+        [
+            min,
+            Point::from([max.u, min.v]),
+            max,
+            Point::from([min.u, max.v]),
+        ]
+    }
+
     /// Merge this AABB with another
     pub fn merged(&self, other: &Self) -> Self {
         self.to_parry().merged(&other.to_parry()).into()
@@ -81,16 +187,6 @@ impl Aabb<2> {
 }
 
 impl Aabb<3> {
-    /// Construct a 3-dimensional AABB from a list of points
-    ///
-    /// The resulting AABB will contain all the points.
-    pub fn from_points(
-        points: impl IntoIterator<Item = impl Into<Point<3>>>,
-    ) -> Self {
-        let points = points.into_iter().map(|point| point.into().to_na());
-        parry3d_f64::bounding_volume::Aabb::from_points(points).into()
-    }
-
     /// Construct a 3-dimensional AABB from a Parry AABB
     pub fn from_parry(aabb: parry3d_f64::bounding_volume::Aabb) -> Self {
         Self {
@@ -108,18 +204,36 @@ impl Aabb<3> {
     }
 
     /// Access the vertices of the AABB
+    ///
+    /// This is the same as [`Aabb::corners_3d`], which documents the order of
+    /// the vertices.
     pub fn vertices(&self) -> [Point<3>; 8] {
-        self.to_parry().vertices().map(Into::into)
+        self.corners_3d()
     }
 
-    /// Compute the center point of the AABB
-    pub fn center(&self) -> Point<3> {
-        self.to_parry().center().into()
-    }
+    /// Access the corners of the AABB
+    ///
+    /// Returns the 4 corners at the minimum z coordinate first, then the 4
+    /// corners at the maximum z coordinate. Each group of 4 is in
+    /// counter-clockwise order (when looking down the z-axis), starting with
+    /// the corner that has the minimum x and y coordinates.
+    ///
+    /// This means that corner `i` and corner `i + 4` are connected by an edge
+    /// parallel to the z-axis.
+    pub fn corners_3d(&self) -> [Point<3>; 8] {
+        let [min, max] = [self.min, self.max];
 
-    /// Compute the size of the AABB
-    pub fn size(&self) -> Vector<3> {
-        self.to_parry().extents().into()
+        [
+            [min.x, min.y, min.z],
+            [max.x, min.y, min.z],
+            [max.x, max.y, min.z],
+            [min.x, max.y, min.z],
+            [min.x, min.y, max.z],
+            [max.x, min.y, max.z],
+            [max.x, max.y, max.z],
+            [min.x, max.y, max.z],
+        ]
+        .map(Point::from)
     }
 
     /// Compute an AABB that includes an additional point
@@ -150,22 +264,201 @@ impl From<parry3d_f64::bounding_volume::Aabb> for Aabb<3> {
 
 #[cfg(test)]
 mod tests {
+    use crate::{Point, Vector};
+
     use super::Aabb;
 
     #[test]
-    fn contains() {
-        let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
+    fn contains_point() {
+        let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]).unwrap();
+
+        assert!(aabb.contains_point([2., 2.]));
+
+        assert!(aabb.contains_point([1., 1.]));
+        assert!(aabb.contains_point([3., 2.]));
+        assert!(aabb.contains_point([2., 3.]));
+
+        assert!(!aabb.contains_point([0., 0.]));
+        assert!(!aabb.contains_point([4., 0.]));
+        assert!(!aabb.contains_point([4., 4.]));
+        assert!(!aabb.contains_point([0., 4.]));
+
+        assert!(!aabb.contains_point([2., 0.]));
+        assert!(!aabb.contains_point([2., 4.]));
+        assert!(!aabb.contains_point([0., 2.]));
+        assert!(!aabb.contains_point([4., 2.]));
+    }
+
+    #[test]
+    fn contains_aabb() {
+        let aabb =
+            Aabb::<3>::from_points([[0., 0., 0.], [2., 2., 2.]]).unwrap();
+
+        let inside =
+            Aabb::<3>::from_points([[0.5, 0.5, 0.5], [1., 1., 1.]]).unwrap();
+        let touching =
+            Aabb::<3>::from_points([[1., 1., 1.], [2., 2., 2.]]).unwrap();
+        let overlapping =
+            Aabb::<3>::from_points([[1., 1., 1.], [3., 3., 3.]]).unwrap();
+        let outside =
+            Aabb::<3>::from_points([[3., 3., 3.], [4., 4., 4.]]).unwrap();
+
+        assert!(aabb.contains_aabb(&aabb));
+        assert!(aabb.contains_aabb(&inside));
+        assert!(aabb.contains_aabb(&touching));
+        assert!(!aabb.contains_aabb(&overlapping));
+        assert!(!aabb.contains_aabb(&outside));
+        assert!(!inside.contains_aabb(&aabb));
+    }
+
+    #[test]
+    fn union() {
+        let a = Aabb::<2>::from_points([[0., 0.], [2., 2.]]).unwrap();
+        let overlapping = Aabb::<2>::from_points([[1., 1.], [3., 3.]]).unwrap();
+        let disjoint = Aabb::<2>::from_points([[4., -1.], [5., 1.]]).unwrap();
+
+        assert_eq!(
+            a.union(&overlapping),
+            Aabb::<2>::from_points([[0., 0.], [3., 3.]]).unwrap(),
+        );
+        assert_eq!(
+            a.union(&disjoint),
+            Aabb::<2>::from_points([[0., -1.], [5., 2.]]).unwrap(),
+        );
+
+        let a = Aabb::<3>::from_points([[0., 0., 0.], [2., 2., 2.]]).unwrap();
+        let overlapping =
+            Aabb::<3>::from_points([[1., 1., 1.], [3., 3., 3.]]).unwrap();
+        let disjoint =
+            Aabb::<3>::from_points([[4., -1., 0.], [5., 1., 1.]]).unwrap();
+
+        assert_eq!(
+            a.union(&overlapping),
+            Aabb::<3>::from_points([[0., 0., 0.], [3., 3., 3.]]).unwrap(),
+        );
+        assert_eq!(
+            a.union(&disjoint),
+            Aabb::<3>::from_points([[0., -1., 0.], [5., 2., 2.]]).unwrap(),
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Aabb::<2>::from_points([[0., 0.], [2., 2.]]).unwrap();
+        let overlapping = Aabb::<2>::from_points([[1., 1.], [3., 3.]]).unwrap();
+        let touching = Aabb::<2>::from_points([[2., 0.], [3., 1.]]).unwrap();
+        let disjoint = Aabb::<2>::from_points([[3., 3.], [4., 4.]]).unwrap();
+
+        assert_eq!(
+            a.intersection(&overlapping),
+            Aabb::<2>::from_points([[1., 1.], [2., 2.]]),
+        );
+        assert_eq!(
+            a.intersection(&touching),
+            Aabb::<2>::from_points([[2., 0.], [2., 1.]]),
+        );
+        assert_eq!(a.intersection(&disjoint), None);
 
-        assert!(aabb.contains([2., 2.]));
+        let a = Aabb::<3>::from_points([[0., 0., 0.], [2., 2., 2.]]).unwrap();
+        let overlapping =
+            Aabb::<3>::from_points([[1., 1., 1.], [3., 3., 3.]]).unwrap();
+        let disjoint =
+            Aabb::<3>::from_points([[0., 0., 3.], [2., 2., 4.]]).unwrap();
 
-        assert!(!aabb.contains([0., 0.]));
-        assert!(!aabb.contains([4., 0.]));
-        assert!(!aabb.contains([4., 4.]));
-        assert!(!aabb.contains([0., 4.]));
+        assert_eq!(
+            a.intersection(&overlapping),
+            Aabb::<3>::from_points([[1., 1., 1.], [2., 2., 2.]]),
+        );
+        assert_eq!(a.intersection(&disjoint), None);
+    }
+
+    #[test]
+    fn expand() {
+        let aabb =
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 2., 3.]]).unwrap();
+
+        let expanded = aabb.expand(0.5);
+        assert_eq!(expanded.center(), aabb.center());
+        assert_eq!(expanded.size(), aabb.size() + Vector::from([1., 1., 1.]));
+
+        let shrunk = aabb.expand(-0.25);
+        assert_eq!(shrunk.center(), aabb.center());
+        assert_eq!(shrunk.size(), aabb.size() - Vector::from([0.5, 0.5, 0.5]));
+
+        let aabb = Aabb::<2>::from_points([[0., 0.], [1., 1.]]).unwrap();
+        assert_eq!(
+            aabb.expand(1.),
+            Aabb::<2>::from_points([[-1., -1.], [2., 2.]]).unwrap(),
+        );
+    }
+
+    #[test]
+    fn from_points() {
+        let single = Aabb::<2>::from_points([[1., 2.]]);
+        let several = Aabb::<2>::from_points([[1., 2.], [-1., 3.], [0., 0.]]);
+        let empty = Aabb::<2>::from_points(Vec::<[f64; 2]>::new());
+
+        assert_eq!(
+            single,
+            Some(Aabb {
+                min: Point::from([1., 2.]),
+                max: Point::from([1., 2.]),
+            }),
+        );
+        assert_eq!(
+            several,
+            Some(Aabb {
+                min: Point::from([-1., 0.]),
+                max: Point::from([1., 3.]),
+            }),
+        );
+        assert_eq!(empty, None);
+    }
+
+    #[test]
+    fn corners_2d() {
+        let aabb = Aabb::<2>::from_points([[0., 0.], [1., 1.]]).unwrap();
+
+        assert_eq!(
+            aabb.corners_2d(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(Point::from),
+        );
+    }
+
+    #[test]
+    fn corners_3d() {
+        let aabb =
+            Aabb::<3>::from_points([[0., 0., 0.], [1., 1., 1.]]).unwrap();
+
+        assert_eq!(
+            aabb.corners_3d(),
+            [
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [1., 1., 0.],
+                [0., 1., 0.],
+                [0., 0., 1.],
+                [1., 0., 1.],
+                [1., 1., 1.],
+                [0., 1., 1.],
+            ]
+            .map(Point::from),
+        );
+        assert_eq!(
+            aabb.vertices(),
+            aabb.to_parry().vertices().map(Point::from),
+        );
+    }
+
+    #[test]
+    fn center_and_size() {
+        let aabb = Aabb::<2>::from_points([[1., 2.], [3., 6.]]).unwrap();
+        assert_eq!(aabb.center(), Point::from([2., 4.]));
+        assert_eq!(aabb.size(), Vector::from([2., 4.]));
 
-        assert!(!aabb.contains([2., 0.]));
-        assert!(!aabb.contains([2., 4.]));
-        assert!(!aabb.contains([0., 2.]));
-        assert!(!aabb.contains([4., 2.]));
+        let aabb =
+            Aabb::<3>::from_points([[-1., 2., 3.], [3., 4., 4.]]).unwrap();
+        assert_eq!(aabb.center(), Point::from([1., 3., 3.5]));
+        assert_eq!(aabb.size(), Vector::from([4., 2., 1.]));
     }
 }
@@ -27,9 +27,16 @@
 //! [`From`]/[`Into`] documentation fails to provide any reasons for its
 //! mandate.
 //!
+//! ## Cargo features
+//!
+//! - `serde`: Implements `Serialize`/`Deserialize` from [serde] for
+//!   [`Scalar`], [`Vector`], [`Point`], and [`Triangle`]. [`Scalar`] is
+//!   serialized as a plain float.
+//!
 //! [Fornjot]: https://www.fornjot.app/
 //! [nalgebra]: https://nalgebra.org/
 //! [Parry]: https://www.parry.rs/
+//! [serde]: https://serde.rs/
 
 mod aabb;
 mod arc;
@@ -53,10 +60,10 @@ pub use self::{
     coordinates::{T, Uv, Xyz},
     line::Line,
     line_segment::LineSegment,
-    point::Point,
+    point::{Point, QuantizedPoint},
     poly_chain::PolyChain,
     scalar::{Scalar, Sign},
     transform::Transform,
-    triangle::{Triangle, Winding},
-    vector::Vector,
+    triangle::{Triangle, Winding, fan, quad_to_triangles},
+    vector::{Vector, orthonormal_basis},
 };
@@ -10,6 +10,7 @@ use super::{
 /// The dimensionality of the point is defined by the const generic `D`
 /// parameter.
 #[derive(Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Point<const D: usize> {
     /// # The coordinates of the point
@@ -56,6 +57,83 @@ impl<const D: usize> Point<D> {
     pub fn distance_to(&self, other: &Self) -> Scalar {
         (self.coords - other.coords).magnitude()
     }
+
+    /// # Linearly interpolate between two points
+    ///
+    /// Returns `self`, if `t` is `0`, and `other`, if `t` is `1`. Values of `t`
+    /// outside of that range extrapolate along the line through both points.
+    pub fn lerp(&self, other: &Self, t: impl Into<Scalar>) -> Self {
+        *self + (*other - *self) * t.into()
+    }
+
+    /// # Compute the squared distance between two points
+    ///
+    /// Cheaper than [`Point::distance_to`], as it doesn't need to compute a
+    /// square root. Use this, if you only need to compare distances.
+    pub fn distance_squared_to(&self, other: &Self) -> Scalar {
+        let difference = self.coords - other.coords;
+        difference.dot(&difference)
+    }
+
+    /// # Determine whether all coordinates of the point are finite
+    ///
+    /// This always returns `true`. See [`Vector::is_finite`].
+    pub fn is_finite(&self) -> bool {
+        self.coords.is_finite()
+    }
+
+    /// # Compute the component-wise minimum of this point and another
+    ///
+    /// See [`Vector::component_min`].
+    pub fn component_min(self, other: impl Into<Self>) -> Self {
+        Self {
+            coords: self.coords.component_min(other.into().coords),
+        }
+    }
+
+    /// # Compute the component-wise maximum of this point and another
+    ///
+    /// See [`Vector::component_max`].
+    pub fn component_max(self, other: impl Into<Self>) -> Self {
+        Self {
+            coords: self.coords.component_max(other.into().coords),
+        }
+    }
+
+    /// # Snap the point to a grid, for hashing or comparing nearby points
+    ///
+    /// Returns the index of the grid point that is nearest to this point, on
+    /// a grid with the provided spacing. Points that are much closer to each
+    /// other than the spacing end up with the same [`QuantizedPoint`], so
+    /// they're treated as equal in a `HashMap` or `BTreeMap`.
+    ///
+    /// Points that are close to the midpoint between two grid points can still
+    /// snap to different grid points, no matter how close they are to each
+    /// other.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if `grid` is not positive.
+    pub fn quantize(&self, grid: impl Into<Scalar>) -> QuantizedPoint<D> {
+        let grid = grid.into();
+        assert!(grid > Scalar::ZERO, "Grid must be positive: {grid:?}");
+
+        QuantizedPoint {
+            indices: self
+                .coords
+                .components
+                .map(|coord| (coord / grid).round().into_f64() as i64),
+        }
+    }
+}
+
+/// # A point that has been snapped to a grid
+///
+/// See [`Point::quantize`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct QuantizedPoint<const D: usize> {
+    /// # The index of the grid point along each axis
+    pub indices: [i64; D],
 }
 
 impl ops::Deref for Point<1> {
@@ -219,3 +297,84 @@ impl<const D: usize> approx::AbsDiffEq for Point<D> {
         self.coords.abs_diff_eq(&other.coords, epsilon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{Point, Scalar};
+
+    #[test]
+    fn distance_to() {
+        let a = Point::from([1., 1.]);
+        let b = Point::from([4., 5.]);
+
+        assert_eq!(a.distance_to(&b), Scalar::from(5.));
+        assert_eq!(b.distance_to(&a), Scalar::from(5.));
+        assert_eq!(a.distance_to(&a), Scalar::ZERO);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Point::from([1., 2., 3.]);
+        let b = Point::from([3., 6., -1.]);
+
+        assert_eq!(a.lerp(&b, 0.), a);
+        assert_eq!(a.lerp(&b, 1.), b);
+        assert_eq!(a.lerp(&b, 0.5), Point::from([2., 4., 1.]));
+        assert_eq!(a.lerp(&b, 2.), Point::from([5., 10., -5.]));
+    }
+
+    #[test]
+    fn distance_squared_to() {
+        let a = Point::from([1., 1.]);
+        let b = Point::from([4., 5.]);
+
+        assert_eq!(a.distance_squared_to(&b), Scalar::from(25.));
+        assert_eq!(b.distance_squared_to(&a), Scalar::from(25.));
+        assert_eq!(a.distance_squared_to(&a), Scalar::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_coordinates_are_rejected() {
+        let _ = Point::from([0., f64::NAN]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn infinite_coordinates_are_rejected() {
+        let _ = Point::from([f64::INFINITY, 0., 0.]);
+    }
+
+    #[test]
+    fn is_finite() {
+        assert!(Point::from([0., -1., f64::MAX]).is_finite());
+    }
+
+    #[test]
+    fn component_min_max() {
+        let a = Point::from([-1., 2., -3.]);
+        let b = Point::from([1., -2., -4.]);
+
+        assert_eq!(a.component_min(b), Point::from([-1., -2., -4.]));
+        assert_eq!(a.component_max(b), Point::from([1., 2., -3.]));
+
+        // `Ord` compares lexicographically, and is not affected.
+        assert_eq!(a.min(b), a);
+        assert_eq!(a.max(b), b);
+    }
+
+    #[test]
+    fn quantize() {
+        let a = Point::from([0.5, 1.5, -2.]);
+        let b = Point::from([0.5 + 1e-12, 1.5, -2. - 1e-12]);
+        assert_ne!(a, b);
+
+        let points = HashSet::from([a.quantize(1e-6), b.quantize(1e-6)]);
+        assert_eq!(points.len(), 1);
+
+        let c = Point::from([0.5 + 1e-3, 1.5, -2.]);
+        assert_ne!(a.quantize(1e-6), c.quantize(1e-6));
+    }
+}
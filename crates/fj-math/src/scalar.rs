@@ -110,6 +110,76 @@ impl Scalar {
         self.value.max(other.into().value).into()
     }
 
+    /// # Compare with another scalar, allowing for a number of ULPs
+    ///
+    /// An ULP (unit in the last place) is the distance between two adjacent
+    /// `f64` values. Unlike an absolute epsilon, a tolerance in ULPs scales
+    /// with the magnitude of the values being compared, so it works just as
+    /// well for values far from zero.
+    ///
+    /// Returns `true`, if there are no more than `max_ulps` representable
+    /// values between `self` and `other`. `0.0` and `-0.0` are considered
+    /// equal.
+    pub fn approx_eq_ulps(self, other: impl Into<Self>, max_ulps: u64) -> bool {
+        // Map the bits to integers that are ordered like the values they
+        // represent, so the difference between them is the number of ULPs.
+        fn ordered_bits(value: f64) -> i64 {
+            let bits = value.to_bits() as i64;
+            if bits < 0 { i64::MIN - bits } else { bits }
+        }
+
+        let [a, b] = [self, other.into()].map(|s| ordered_bits(s.value));
+        a.abs_diff(b) <= max_ulps
+    }
+
+    /// # Restrict the scalar value to the range `min..=max`
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if `min` is larger than `max`.
+    pub fn clamp(self, min: impl Into<Self>, max: impl Into<Self>) -> Self {
+        let [min, max] = [min.into(), max.into()];
+        assert!(min <= max, "Invalid range for clamping: {min:?}..={max:?}");
+
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// # Restrict the scalar value to the range `0..=1`
+    pub fn clamp01(self) -> Self {
+        self.clamp(Self::ZERO, Self::ONE)
+    }
+
+    /// # Linearly interpolate between this and another scalar value
+    ///
+    /// Returns `self`, if `t` is `0`, and `other`, if `t` is `1`. Values of `t`
+    /// outside of that range extrapolate.
+    pub fn lerp(self, other: impl Into<Self>, t: impl Into<Self>) -> Self {
+        self + (other.into() - self) * t.into()
+    }
+
+    /// # Compute where the scalar value falls between `a` and `b`
+    ///
+    /// This is the inverse of [`Scalar::lerp`]. Returns `0`, if the value is
+    /// equal to `a`, and `1`, if it is equal to `b`. Values outside of the
+    /// range result in a parameter outside of `0..=1`.
+    ///
+    /// Returns `0`, if `a` and `b` are equal.
+    pub fn inverse_lerp(self, a: impl Into<Self>, b: impl Into<Self>) -> Self {
+        let [a, b] = [a.into(), b.into()];
+
+        if a == b {
+            return Self::ZERO;
+        }
+
+        (self - a) / (b - a)
+    }
+
     /// # Compute the largest integer smaller than or equal to the scalar value
     pub fn floor(self) -> Self {
         self.value.floor().into()
@@ -633,6 +703,34 @@ impl approx::AbsDiffEq for Scalar {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = f64::deserialize(deserializer)?;
+
+        if !value.is_finite() {
+            return Err(serde::de::Error::custom(format!(
+                "`Scalar` value must be finite. Value: `{value}`"
+            )));
+        }
+
+        Ok(Self { value })
+    }
+}
+
 /// # The sign of a [`Scalar`]
 ///
 /// See [`Scalar::sign`]
@@ -661,3 +759,80 @@ impl Sign {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Scalar;
+
+    #[test]
+    fn clamp() {
+        let [min, max] = [-1., 2.];
+
+        assert_eq!(Scalar::from(-3.).clamp(min, max), Scalar::from(min));
+        assert_eq!(Scalar::from(0.5).clamp(min, max), Scalar::from(0.5));
+        assert_eq!(Scalar::from(3.).clamp(min, max), Scalar::from(max));
+    }
+
+    #[test]
+    fn clamp01() {
+        assert_eq!(Scalar::from(-0.5).clamp01(), Scalar::ZERO);
+        assert_eq!(Scalar::from(0.5).clamp01(), Scalar::from(0.5));
+        assert_eq!(Scalar::from(1.5).clamp01(), Scalar::ONE);
+    }
+
+    #[test]
+    fn approx_eq_ulps() {
+        for value in [1., 1e6, -1e6] {
+            let a = Scalar::from(value);
+            let next = Scalar::from(f64::from_bits(a.into_f64().to_bits() + 1));
+            let far = Scalar::from(f64::from_bits(a.into_f64().to_bits() + 5));
+
+            assert!(a.approx_eq_ulps(a, 0));
+            assert!(!a.approx_eq_ulps(next, 0));
+            assert!(a.approx_eq_ulps(next, 1));
+            assert!(next.approx_eq_ulps(a, 1));
+            assert!(!a.approx_eq_ulps(far, 4));
+            assert!(a.approx_eq_ulps(far, 5));
+        }
+
+        // The tolerance scales with the magnitude of the values. An error that
+        // is a few ULPs near 1e6 would be huge near 1.
+        let error = 1e-10;
+        assert!(Scalar::from(1e6).approx_eq_ulps(1e6 + error, 4));
+        assert!(!Scalar::from(1.).approx_eq_ulps(1. + error, 4));
+
+        assert!(Scalar::from(0.).approx_eq_ulps(-0., 0));
+        assert!(!Scalar::from(1.).approx_eq_ulps(-1., 4));
+    }
+
+    #[test]
+    fn lerp() {
+        let [a, b] = [Scalar::from(1.), Scalar::from(3.)];
+
+        assert_eq!(a.lerp(b, 0.), a);
+        assert_eq!(a.lerp(b, 0.5), Scalar::TWO);
+        assert_eq!(a.lerp(b, 1.), b);
+        assert_eq!(a.lerp(b, -1.), Scalar::from(-1.));
+        assert_eq!(a.lerp(b, 2.), Scalar::from(5.));
+    }
+
+    #[test]
+    fn inverse_lerp() {
+        let [a, b] = [1., 3.];
+
+        assert_eq!(Scalar::from(1.).inverse_lerp(a, b), Scalar::ZERO);
+        assert_eq!(Scalar::from(2.).inverse_lerp(a, b), Scalar::from(0.5));
+        assert_eq!(Scalar::from(3.).inverse_lerp(a, b), Scalar::ONE);
+        assert_eq!(Scalar::from(-1.).inverse_lerp(a, b), Scalar::from(-1.));
+        assert_eq!(Scalar::from(5.).inverse_lerp(a, b), Scalar::TWO);
+
+        // Equal bounds should be handled as well.
+        assert_eq!(Scalar::from(2.).inverse_lerp(a, a), Scalar::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_results_are_rejected() {
+        let _ = Scalar::ZERO / Scalar::ZERO;
+    }
+}
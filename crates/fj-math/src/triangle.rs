@@ -1,7 +1,13 @@
+use std::{cmp, ops};
+
 use approx::AbsDiffEq;
-use parry3d_f64::query::{Ray, RayCast as _};
+use parry2d_f64::query::RayCast as _;
+use parry3d_f64::{
+    math::Isometry,
+    query::{Ray, RayCast as _},
+};
 
-use crate::Vector;
+use crate::{Aabb, Transform, Vector};
 
 use super::{Point, Scalar};
 
@@ -10,6 +16,7 @@ use super::{Point, Scalar};
 /// The dimensionality of the triangle is defined by the const generic `D`
 /// parameter.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Triangle<const D: usize> {
     /// # The points that make up the triangle
@@ -32,15 +39,55 @@ impl<const D: usize> Triangle<D> {
     /// ## Implementation Note
     ///
     /// Right now, this function computes the area of the triangle, and compares
-    /// it against [`Scalar`]'s default epsilon value. This might not be
-    /// flexible enough for all use cases.
+    /// it against [`Scalar`]'s default epsilon value. If that is not flexible
+    /// enough for your use case, use [`Triangle::is_valid_with_epsilon`].
     ///
-    /// Long-term, it might become necessary to add some way to override the
-    /// epsilon value used within this function.
+    /// There's no need to check for non-finite coordinates here. [`Scalar`]
+    /// rejects NaN and infinite values on construction, so a [`Point`] can't
+    /// contain them (see [`Point::is_finite`]).
     pub fn is_valid(&self) -> bool {
+        self.is_valid_with_epsilon(Scalar::default_epsilon())
+    }
+
+    /// # Determine whether the triangle is valid, using a custom epsilon
+    ///
+    /// Works like [`Triangle::is_valid`], but compares the area of the triangle
+    /// against the provided `epsilon` instead of [`Scalar`]'s default epsilon
+    /// value.
+    pub fn is_valid_with_epsilon(&self, epsilon: impl Into<Scalar>) -> bool {
         let [a, b, c] = self.points;
         let area = (b - a).outer(&(c - a)).magnitude();
-        area > Scalar::default_epsilon()
+        area > epsilon.into()
+    }
+
+    /// # Compute the area of the triangle
+    pub fn area(&self) -> Scalar {
+        let [a, b, c] = self.points;
+        (b - a).outer(&(c - a)).magnitude() / 2.
+    }
+
+    /// # Compute the axis-aligned bounding box of the triangle
+    pub fn bounding_box(&self) -> Aabb<D> {
+        let [a, b, c] = self.points;
+
+        let mut aabb = Aabb { min: a, max: a };
+
+        for point in [b, c] {
+            let components = aabb
+                .min
+                .coords
+                .components
+                .iter_mut()
+                .zip(aabb.max.coords.components.iter_mut())
+                .zip(point.coords.components);
+
+            for ((min, max), p) in components {
+                *min = cmp::min(*min, p);
+                *max = cmp::max(*max, p);
+            }
+        }
+
+        aabb
     }
 
     /// # Compute the center point of the triangle
@@ -50,6 +97,242 @@ impl<const D: usize> Triangle<D> {
         Point { coords }
     }
 
+    /// # Access the edges of the triangle
+    ///
+    /// Returns the edges `ab`, `bc`, and `ca`, in that order, as pairs of
+    /// start and end point.
+    pub fn edges(&self) -> [[Point<D>; 2]; 3] {
+        let [a, b, c] = self.points;
+        [[a, b], [b, c], [c, a]]
+    }
+
+    /// # Compute the lengths of the triangle's edges
+    ///
+    /// Returns the lengths of the edges `ab`, `bc`, and `ca`, in that order.
+    pub fn edge_lengths(&self) -> [Scalar; 3] {
+        self.edges().map(|[start, end]| start.distance_to(&end))
+    }
+
+    /// # Compute the perimeter of the triangle
+    pub fn perimeter(&self) -> Scalar {
+        let [ab, bc, ca] = self.edge_lengths();
+        ab + bc + ca
+    }
+
+    /// # Compute the interior angles of the triangle
+    ///
+    /// Returns the angles at the points `a`, `b`, and `c`, in that order, in
+    /// radians.
+    ///
+    /// If an edge of the triangle has zero length, the angles at its points
+    /// are zero (see [`Vector::angle_to`]). In that case, the angles don't sum
+    /// up to π.
+    pub fn angles(&self) -> [Scalar; 3] {
+        let [a, b, c] = self.points;
+
+        [[a, b, c], [b, c, a], [c, a, b]].map(|[vertex, next, prev]| {
+            (next - vertex).angle_to(&(prev - vertex))
+        })
+    }
+
+    /// # Determine whether the largest angle of the triangle is obtuse
+    ///
+    /// Angles that are within [`Scalar::default_epsilon`] of a right angle are
+    /// not considered obtuse. Use [`Triangle::is_right`], to check for those
+    /// with a custom epsilon.
+    pub fn is_obtuse(&self) -> bool {
+        self.largest_angle() > Scalar::PI / 2. + Scalar::default_epsilon()
+    }
+
+    /// # Determine whether all angles of the triangle are acute
+    ///
+    /// Angles that are within [`Scalar::default_epsilon`] of a right angle are
+    /// not considered acute. Use [`Triangle::is_right`], to check for those
+    /// with a custom epsilon.
+    ///
+    /// A degenerate triangle is never acute, since its largest angle is π, or
+    /// zero, if all of its points coincide (see [`Triangle::angles`]).
+    pub fn is_acute(&self) -> bool {
+        let largest_angle = self.largest_angle();
+
+        largest_angle > Scalar::ZERO
+            && largest_angle < Scalar::PI / 2. - Scalar::default_epsilon()
+    }
+
+    /// # Determine whether the largest angle of the triangle is a right angle
+    ///
+    /// The largest angle must be within `epsilon` of π/2.
+    pub fn is_right(&self, epsilon: impl Into<Scalar>) -> bool {
+        (self.largest_angle() - Scalar::PI / 2.).abs() <= epsilon.into()
+    }
+
+    fn largest_angle(&self) -> Scalar {
+        let [a, b, c] = self.angles();
+        a.max(b).max(c)
+    }
+
+    /// # Compute the center of the triangle's circumscribed circle
+    ///
+    /// The circumcenter is the point that has the same distance to all three
+    /// points of the triangle. It is always in the plane of the triangle.
+    ///
+    /// Returns `None`, if the triangle is not valid (see
+    /// [`Triangle::is_valid`]). A degenerate triangle doesn't have a
+    /// circumscribed circle.
+    pub fn circumcenter(&self) -> Option<Point<D>> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let [a, b, c] = self.points;
+
+        let ab = b - a;
+        let ac = c - a;
+
+        let d00 = ab.dot(&ab);
+        let d01 = ab.dot(&ac);
+        let d11 = ac.dot(&ac);
+
+        let denom = (d00 * d11 - d01 * d01) * 2.;
+
+        let s = d11 * (d00 - d01) / denom;
+        let t = d00 * (d11 - d01) / denom;
+
+        Some(a + ab * s + ac * t)
+    }
+
+    /// # Compute the radius of the triangle's circumscribed circle
+    ///
+    /// Returns `None`, if the triangle is not valid. See
+    /// [`Triangle::circumcenter`].
+    pub fn circumradius(&self) -> Option<Scalar> {
+        let [a, _, _] = self.points;
+        let circumcenter = self.circumcenter()?;
+
+        Some(circumcenter.distance_to(&a))
+    }
+
+    /// # Compute the center of the triangle's inscribed circle
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if all points of the triangle are coincident.
+    pub fn incenter(&self) -> Point<D> {
+        let [a, b, c] = self.points;
+        let [ab, bc, ca] = self.edge_lengths();
+
+        let coords =
+            (a.coords * bc + b.coords * ca + c.coords * ab) / self.perimeter();
+        Point { coords }
+    }
+
+    /// # Compute the radius of the triangle's inscribed circle
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if all points of the triangle are coincident.
+    pub fn inradius(&self) -> Scalar {
+        let semi_perimeter = self.perimeter() / 2.;
+        self.area() / semi_perimeter
+    }
+
+    /// # Compute the aspect ratio of the triangle
+    ///
+    /// The aspect ratio is a measure of the triangle's quality. It is defined
+    /// as the ratio of the longest edge to the shortest altitude, normalized
+    /// such that an equilateral triangle has an aspect ratio of `1`. Any other
+    /// triangle has a larger aspect ratio, and the more sliver-like a triangle
+    /// is, the larger its aspect ratio.
+    ///
+    /// Returns [`Scalar::MAX`], if the triangle is not valid (see
+    /// [`Triangle::is_valid`]).
+    pub fn aspect_ratio(&self) -> Scalar {
+        if !self.is_valid() {
+            return Scalar::MAX;
+        }
+
+        let [ab, bc, ca] = self.edge_lengths();
+
+        let longest_edge = ab.max(bc).max(ca);
+        let shortest_altitude = self.area() * 2. / longest_edge;
+
+        longest_edge / shortest_altitude * (3f64.sqrt() / 2.)
+    }
+
+    /// # Scale the triangle about its center point
+    ///
+    /// Moves each point of the triangle along the vector from the center point
+    /// (see [`Triangle::center`]), by the provided factor. A factor of `1`
+    /// leaves the triangle unchanged, a factor of `0` collapses all of its
+    /// points into the center point.
+    pub fn scale_about_center(&self, factor: impl Into<Scalar>) -> Self {
+        let factor = factor.into();
+        let center = self.center();
+
+        let points =
+            self.points.map(|point| center + (point - center) * factor);
+
+        Self { points }
+    }
+
+    /// # Subdivide the triangle into four smaller triangles
+    ///
+    /// Splits the triangle at the midpoints of its edges. Returns the three
+    /// triangles at the corners `a`, `b`, and `c` (in that order), followed by
+    /// the central triangle. All of them have the same winding as the original
+    /// triangle.
+    pub fn subdivide(&self) -> [Self; 4] {
+        let [a, b, c] = self.points;
+        let [ab, bc, ca] =
+            self.edges().map(|[start, end]| start.lerp(&end, 0.5));
+
+        [[a, ab, ca], [ab, b, bc], [ca, bc, c], [ab, bc, ca]]
+            .map(|points| Self { points })
+    }
+
+    /// # Tessellate the triangle into a grid of smaller triangles
+    ///
+    /// Splits each edge of the triangle into `level` segments of equal length,
+    /// and returns the `level²` triangles that connect the resulting grid of
+    /// points. All of them have the same winding as the original triangle.
+    ///
+    /// Levels `0` and `1` both return the original triangle.
+    pub fn tessellate(&self, level: usize) -> Vec<Self> {
+        if level <= 1 {
+            return vec![*self];
+        }
+
+        // The grid point that is `i` steps towards `b` and `j` steps towards
+        // `c`, starting from `a`.
+        let point = |i: usize, j: usize| {
+            let [wb, wc] =
+                [i, j].map(|w| Scalar::from(w as f64 / level as f64));
+            self.point_from_barycentric_coords([Scalar::ONE - wb - wc, wb, wc])
+        };
+
+        let mut triangles = Vec::with_capacity(level * level);
+
+        for i in 0..level {
+            for j in 0..level - i {
+                triangles.push(Self {
+                    points: [point(i, j), point(i + 1, j), point(i, j + 1)],
+                });
+
+                if i + j + 1 < level {
+                    triangles.push(Self {
+                        points: [
+                            point(i + 1, j),
+                            point(i + 1, j + 1),
+                            point(i, j + 1),
+                        ],
+                    });
+                }
+            }
+        }
+
+        triangles
+    }
+
     /// # Convert a set of barycentric coordinates on the triangle into a point
     pub fn point_from_barycentric_coords(
         &self,
@@ -60,6 +343,24 @@ impl<const D: usize> Triangle<D> {
         Point { coords }
     }
 
+    /// # Interpolate per-point values, using barycentric coordinates
+    ///
+    /// This works like [`Triangle::point_from_barycentric_coords`], but for
+    /// arbitrary values that are associated with the triangle's points, like
+    /// colors, normals, or texture coordinates. Combine it with
+    /// [`Triangle::point_to_barycentric_coords`] to look up the value at a
+    /// given point.
+    pub fn interpolate<T>(
+        &self,
+        [wa, wb, wc]: [Scalar; 3],
+        [a, b, c]: [T; 3],
+    ) -> T
+    where
+        T: ops::Add<Output = T> + ops::Mul<Scalar, Output = T>,
+    {
+        a * wa + b * wb + c * wc
+    }
+
     /// # Project a point into the triangle
     ///
     /// Returns barycentric coordinates of the projected point.
@@ -97,6 +398,10 @@ impl<const D: usize> Triangle<D> {
     }
 
     /// # Compute the point on the triangle that is closest to the provided `p`
+    ///
+    /// Unlike [`Triangle::point_to_barycentric_coords`], this considers the
+    /// edges and vertices of the triangle. The returned point is always on
+    /// the triangle, even if `p` is outside of it.
     pub fn closest_point(&self, p: impl Into<Point<D>>) -> Point<D> {
         // From Real-Time Collision Detection by Christer Ericson, pages 141 and
         // 142.
@@ -163,6 +468,28 @@ impl<const D: usize> Triangle<D> {
         a + ab * v + ac * w
     }
 
+    /// # Flip the triangle
+    ///
+    /// Returns a new `Triangle` instance with the same points, but with the
+    /// second and third point swapped. This reverses the winding of the
+    /// triangle, and flips its normal.
+    pub fn flip(&self) -> Self {
+        let [a, b, c] = self.points;
+        Self { points: [a, c, b] }
+    }
+
+    /// # Determine whether the triangle is approximately equal to another
+    ///
+    /// Compares the corresponding points of both triangles, and considers them
+    /// equal, if the distance between them is within `epsilon` on each axis.
+    ///
+    /// The order of the points is considered, meaning two triangles with the
+    /// same points in a different order are not equal. If that is not what you
+    /// want, call [`Triangle::normalize`] on both triangles first.
+    pub fn approx_eq(&self, other: &Self, epsilon: impl Into<Scalar>) -> bool {
+        self.abs_diff_eq(other, epsilon.into())
+    }
+
     /// # Normalize the triangle
     ///
     /// Returns a new `Triangle` instance with the same points, but the points
@@ -175,19 +502,88 @@ impl<const D: usize> Triangle<D> {
         self.points.sort();
         self
     }
+
+    /// # Normalize the triangle, treating nearly equal coordinates as equal
+    ///
+    /// Works like [`Triangle::normalize`], but the points are ordered as if
+    /// their coordinates were snapped to a grid with a spacing of `epsilon`.
+    /// This means two triangles whose points differ by much less than
+    /// `epsilon` end up with the same order of points. The coordinates of the
+    /// returned points are not changed.
+    ///
+    /// Coordinates that are close to the midpoint between two grid lines can
+    /// still snap to different grid lines, so this doesn't work for all
+    /// near-coincident points.
+    pub fn normalize_with_tolerance(
+        mut self,
+        epsilon: impl Into<Scalar>,
+    ) -> Self {
+        let epsilon = epsilon.into();
+
+        self.points.sort_by_key(|point| {
+            point
+                .coords
+                .components
+                .map(|coord| (coord / epsilon).round())
+        });
+        self
+    }
 }
 
 impl Triangle<2> {
+    /// # Convert the triangle to a Parry triangle
+    pub fn to_parry(self) -> parry2d_f64::shape::Triangle {
+        self.points.map(|vertex| vertex.to_na()).into()
+    }
+
+    /// # Convert the triangle into a 3D triangle in the xy-plane
+    ///
+    /// The z coordinate of each point is set to zero. The inverse of this is
+    /// [`Triangle::drop_z`].
+    pub fn to_xy(&self) -> Triangle<3> {
+        Triangle {
+            points: self.points.map(Point::to_xyz),
+        }
+    }
+
+    /// # Cast a ray against the triangle
+    ///
+    /// Returns the time of impact of the nearest intersection with the
+    /// boundary of the triangle, if any. If `origin` is inside the triangle,
+    /// the time of impact is zero.
+    pub fn cast_local_ray(
+        &self,
+        origin: Point<2>,
+        dir: Vector<2>,
+        max_toi: f64,
+    ) -> Option<Scalar> {
+        let ray = parry2d_f64::query::Ray {
+            origin: origin.to_na(),
+            dir: dir.to_na(),
+        };
+
+        self.to_parry()
+            .cast_local_ray(&ray, max_toi, true)
+            .map(Into::into)
+    }
+
+    /// # Compute the signed area of the triangle
+    ///
+    /// The magnitude of the signed area is the same as [`Triangle::area`]. The
+    /// sign depends on the winding of the triangle: It is positive for
+    /// counter-clockwise triangles, and negative for clockwise ones.
+    pub fn signed_area(&self) -> Scalar {
+        let [a, b, c] = self.points;
+        (b - a).cross2d(&(c - a)) / 2.
+    }
+
     /// # Compute the winding of the triangle
     ///
     /// A winding can only be computed, if the triangle is valid. Returns
     /// `None`, if it isn't.
     pub fn winding(&self) -> Option<Winding> {
-        let [pa, pb, pc] = self.points.map(|point| robust::Coord {
-            x: point.u,
-            y: point.v,
-        });
-        let orient2d = robust::orient2d(pa, pb, pc);
+        let [a, b, c] = self.points;
+        let orient2d = orient2d(a, b, c);
 
         if orient2d < 0. {
             return Some(Winding::Cw);
@@ -198,6 +594,27 @@ impl Triangle<2> {
 
         None
     }
+
+    /// # Determine whether the triangle contains the provided point
+    ///
+    /// Points on the edges or vertices of the triangle count as being
+    /// contained. Degenerate triangles don't contain any points.
+    pub fn contains_point(&self, point: impl Into<Point<2>>) -> bool {
+        let point = point.into();
+
+        let Some(winding) = self.winding() else {
+            return false;
+        };
+
+        self.edges().into_iter().all(|[start, end]| {
+            let orient2d = orient2d(start, end, point);
+
+            match winding {
+                Winding::Ccw => orient2d >= 0.,
+                Winding::Cw => orient2d <= 0.,
+            }
+        })
+    }
 }
 
 impl Triangle<3> {
@@ -206,6 +623,18 @@ impl Triangle<3> {
         self.points.map(|vertex| vertex.to_na()).into()
     }
 
+    /// # Convert the triangle into a 2D triangle, by discarding z
+    ///
+    /// This projects the triangle into the xy-plane. The inverse of this, for
+    /// triangles that are already in the xy-plane, is [`Triangle::to_xy`].
+    pub fn drop_z(&self) -> Triangle<2> {
+        Triangle {
+            points: self.points.map(|point| Point {
+                coords: point.coords.xy(),
+            }),
+        }
+    }
+
     /// # Cast a ray against the Triangle
     pub fn cast_local_ray(
         &self,
@@ -224,13 +653,120 @@ impl Triangle<3> {
             .map(Into::into)
     }
 
+    /// # Transform the triangle
+    ///
+    /// This is equivalent to [`Transform::transform_triangle`]. The normal of
+    /// the returned triangle is consistent with the transformed points, so it
+    /// should be recomputed via [`Triangle::normal`], not by transforming the
+    /// normal of the original triangle.
+    pub fn transform(&self, transform: &Transform) -> Triangle<3> {
+        transform.transform_triangle(self)
+    }
+
+    /// # Determine whether the triangle intersects another
+    ///
+    /// Triangles are considered closed, meaning triangles that only touch
+    /// (share an edge or a vertex, or touch along an edge with another
+    /// triangle's face) are considered intersecting. This is also true for
+    /// coplanar triangles, as long as they overlap or touch.
+    pub fn intersects(&self, other: &Triangle<3>) -> bool {
+        let identity = Isometry::identity();
+
+        parry3d_f64::query::intersection_test(
+            &identity,
+            &self.to_parry(),
+            &identity,
+            &other.to_parry(),
+        )
+        .expect("Triangle/triangle intersection tests are supported")
+    }
+
     /// # Compute the triangle's normal
+    ///
+    /// The normal points towards the side from which the points of the
+    /// triangle appear in counter-clockwise order.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the triangle is degenerate.
     pub fn normal(&self) -> Vector<3> {
-        self.to_parry()
-            .normal()
-            .expect("triangle is valid (validated on construction)")
-            .into_inner()
-            .into()
+        let [a, b, c] = self.points;
+        let normal = (b - a).cross(&(c - a));
+
+        assert!(
+            !normal.magnitude().is_zero(),
+            "Can't compute normal of degenerate triangle: {self:?}",
+        );
+
+        normal.normalize()
+    }
+
+    /// # Determine the orientation of the triangle, as seen from a point
+    ///
+    /// Returns [`Winding::Ccw`], if the point is on the side of the triangle's
+    /// plane that [`Triangle::normal`] points towards, and [`Winding::Cw`], if
+    /// it is on the other side. Returns `None`, if the point is on the plane,
+    /// or the triangle is degenerate.
+    ///
+    /// Uses exact arithmetic, so the result is correct, no matter how close
+    /// the point is to the plane.
+    pub fn orientation_of(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Option<Winding> {
+        let [a, b, c] = self.points;
+        let orient3d = orient3d(a, b, c, point.into());
+
+        // `orient3d` is positive, if the point is below the plane, meaning on
+        // the side from which the triangle appears clockwise.
+        if orient3d > 0. {
+            return Some(Winding::Cw);
+        }
+        if orient3d < 0. {
+            return Some(Winding::Ccw);
+        }
+
+        None
+    }
+
+    /// # Determine the winding of the triangle, relative to a reference normal
+    ///
+    /// Returns [`Winding::Ccw`], if [`Triangle::normal`] points in the same
+    /// general direction as `reference_normal` (their dot product is
+    /// positive), meaning the triangle appears counter-clockwise when viewed
+    /// from the side the reference normal points towards. Returns
+    /// [`Winding::Cw`] otherwise, and `None`, if the triangle is degenerate.
+    pub fn winding_about(
+        &self,
+        reference_normal: impl Into<Vector<3>>,
+    ) -> Option<Winding> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        if self.normal().dot(&reference_normal.into()) > Scalar::ZERO {
+            Some(Winding::Ccw)
+        } else {
+            Some(Winding::Cw)
+        }
+    }
+
+    /// # Compute the signed distance from the triangle to a point
+    ///
+    /// The magnitude is the distance to the closest point on the triangle (see
+    /// [`Triangle::closest_point`]), which might be on one of its edges or
+    /// vertices. The sign depends only on which side of the triangle's plane
+    /// the point is on (see [`Triangle::orientation_of`]): It is positive on
+    /// the side that [`Triangle::normal`] points towards, negative on the
+    /// other side. Points that are on the plane have a non-negative distance.
+    pub fn signed_distance(&self, point: impl Into<Point<3>>) -> Scalar {
+        let point = point.into();
+        let distance = point.distance_to(&self.closest_point(point));
+
+        match self.orientation_of(point) {
+            Some(winding) => distance * winding.sign(),
+            None => distance,
+        }
     }
 }
 
@@ -243,6 +779,84 @@ where
     }
 }
 
+fn orient2d(a: Point<2>, b: Point<2>, c: Point<2>) -> f64 {
+    let [pa, pb, pc] = [a, b, c].map(|point| robust::Coord {
+        x: point.u,
+        y: point.v,
+    });
+    robust::orient2d(pa, pb, pc)
+}
+
+fn orient3d(a: Point<3>, b: Point<3>, c: Point<3>, d: Point<3>) -> f64 {
+    let [pa, pb, pc, pd] = [a, b, c, d].map(|point| robust::Coord3D {
+        x: point.x,
+        y: point.y,
+        z: point.z,
+    });
+    robust::orient3d(pa, pb, pc, pd)
+}
+
+impl<const D: usize> approx::AbsDiffEq for Triangle<D> {
+    type Epsilon = <Point<D> as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.points.abs_diff_eq(&other.points, epsilon)
+    }
+}
+
+/// # Split a quad into two triangles
+///
+/// The corners of the quad are expected in order, going around its boundary,
+/// so that `a` is opposite of `c` and `b` is opposite of `d`. The quad is split
+/// along the shorter of the two diagonals, which results in better-shaped
+/// triangles. Both triangles have the same winding as the quad.
+pub fn quad_to_triangles<const D: usize>(
+    [a, b, c, d]: [Point<D>; 4],
+) -> [Triangle<D>; 2] {
+    let triangles = if a.distance_to(&c) <= b.distance_to(&d) {
+        [[a, b, c], [a, c, d]]
+    } else {
+        [[a, b, d], [b, c, d]]
+    };
+
+    triangles.map(|points| Triangle { points })
+}
+
+/// # Connect a center point to a ring of points, forming a triangle fan
+///
+/// Each triangle connects the center to two consecutive points of the ring.
+/// If `closed` is `true`, another triangle connects the last point of the ring
+/// back to the first. All triangles have the same winding, which is the
+/// direction in which the ring goes around the center.
+///
+/// Returns no triangles, if the ring has less than two points. A ring with
+/// only two points is never closed, as that would result in the same triangle
+/// twice, with opposite windings.
+pub fn fan<const D: usize>(
+    center: Point<D>,
+    ring: &[Point<D>],
+    closed: bool,
+) -> Vec<Triangle<D>> {
+    let mut triangles = ring
+        .windows(2)
+        .map(|window| Triangle {
+            points: [center, window[0], window[1]],
+        })
+        .collect::<Vec<_>>();
+
+    if closed && ring.len() > 2 {
+        triangles.push(Triangle {
+            points: [center, ring[ring.len() - 1], ring[0]],
+        });
+    }
+
+    triangles
+}
+
 /// # Winding direction of a triangle.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Winding {
@@ -263,13 +877,34 @@ impl Winding {
     pub fn is_cw(&self) -> bool {
         matches!(self, Self::Cw)
     }
+
+    /// # Convert the winding into a sign
+    ///
+    /// Returns `1` for counter-clockwise winding, `-1` for clockwise winding.
+    /// This matches the sign of [`Triangle::signed_area`].
+    pub fn sign(&self) -> Scalar {
+        match self {
+            Self::Ccw => Scalar::ONE,
+            Self::Cw => -Scalar::ONE,
+        }
+    }
+
+    /// # Return the opposite winding
+    pub fn reverse(&self) -> Self {
+        match self {
+            Self::Ccw => Self::Cw,
+            Self::Cw => Self::Ccw,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use approx::assert_abs_diff_eq;
 
-    use super::Triangle;
+    use crate::{Aabb, Point, Scalar, Transform, Vector};
+
+    use super::{Triangle, Winding};
 
     #[test]
     fn valid_triangle_2d() {
@@ -307,10 +942,639 @@ mod tests {
         assert!(!Triangle::from_points([a, b, c]).is_valid());
     }
 
+    #[test]
+    fn valid_triangle_with_epsilon() {
+        let a = Point::from([0.0, 0.0]);
+        let b = Point::from([1.0, 0.0]);
+        let c = Point::from([0.0, 1e-6]);
+
+        let triangle = Triangle::from_points([a, b, c]);
+
+        assert!(triangle.is_valid_with_epsilon(1e-9));
+        assert!(!triangle.is_valid_with_epsilon(1e-3));
+    }
+
+    #[test]
+    fn area() {
+        let triangle_2d = Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+        let triangle_3d =
+            Triangle::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+
+        assert_eq!(triangle_2d.area(), Scalar::from(0.5));
+        assert_eq!(triangle_3d.area(), Scalar::from(0.5));
+    }
+
+    #[test]
+    fn signed_area() {
+        let ccw = Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+        let [a, b, c] = ccw.points;
+        let cw = Triangle::from_points([a, c, b]);
+
+        assert_eq!(ccw.signed_area(), Scalar::from(0.5));
+        assert_eq!(cw.signed_area(), Scalar::from(-0.5));
+
+        assert_eq!(ccw.signed_area().abs(), ccw.area());
+        assert_eq!(cw.signed_area().abs(), cw.area());
+    }
+
+    #[test]
+    fn closest_point() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+
+        // over the face
+        assert_eq!(
+            triangle.closest_point([0.5, 0.5, 1.0]),
+            Point::from([0.5, 0.5, 0.0]),
+        );
+
+        // over an edge
+        assert_eq!(
+            triangle.closest_point([1.0, -1.0, 1.0]),
+            Point::from([1.0, 0.0, 0.0]),
+        );
+
+        // beyond a vertex
+        assert_eq!(
+            triangle.closest_point([3.0, -1.0, 0.0]),
+            Point::from([2.0, 0.0, 0.0]),
+        );
+    }
+
+    #[test]
+    fn contains_point() {
+        let ccw = Triangle::from([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]]);
+        let [a, b, c] = ccw.points;
+        let cw = Triangle::from_points([a, c, b]);
+
+        for triangle in [ccw, cw] {
+            // inside
+            assert!(triangle.contains_point([0.5, 0.5]));
+
+            // on edges
+            assert!(triangle.contains_point([1.0, 0.0]));
+            assert!(triangle.contains_point([1.0, 1.0]));
+            assert!(triangle.contains_point([0.0, 1.0]));
+
+            // on vertices
+            assert!(triangle.contains_point(a));
+            assert!(triangle.contains_point(b));
+            assert!(triangle.contains_point(c));
+
+            // outside
+            assert!(!triangle.contains_point([-1.0, 0.5]));
+            assert!(!triangle.contains_point([1.5, 1.5]));
+            assert!(!triangle.contains_point([0.5, -0.5]));
+        }
+    }
+
+    #[test]
+    fn edges() {
+        let triangle = Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+        let [a, b, c] = triangle.points;
+
+        assert_eq!(triangle.edges(), [[a, b], [b, c], [c, a]]);
+    }
+
+    #[test]
+    fn edge_lengths() {
+        let triangle = Triangle::from([[0.0, 0.0], [3.0, 0.0], [3.0, 4.0]]);
+
+        assert_eq!(triangle.edge_lengths(), [3.0, 4.0, 5.0].map(Scalar::from));
+        assert_eq!(triangle.perimeter(), Scalar::from(12.0));
+    }
+
+    #[test]
+    fn angles() {
+        let triangle = Triangle::from([[0.0, 0.0], [3.0, 0.0], [3.0, 4.0]]);
+        let [a, b, c] = triangle.angles();
+
+        assert_abs_diff_eq!(b, Scalar::PI / 2.);
+        assert_abs_diff_eq!(a + b + c, Scalar::PI);
+    }
+
+    #[test]
+    fn angle_classification() {
+        let obtuse = Triangle::from([[0., 0.], [4., 0.], [-1., 1.]]);
+        let acute = Triangle::from([[0., 0.], [2., 0.], [1., 1.5]]);
+        let right = Triangle::from([[0., 0.], [3., 0.], [3., 4.]]);
+
+        assert!(obtuse.is_obtuse());
+        assert!(!obtuse.is_acute());
+        assert!(!obtuse.is_right(1e-12));
+
+        assert!(!acute.is_obtuse());
+        assert!(acute.is_acute());
+        assert!(!acute.is_right(1e-12));
+
+        assert!(!right.is_obtuse());
+        assert!(!right.is_acute());
+        assert!(right.is_right(1e-12));
+
+        let almost_right = Triangle::from([[0., 0.], [3., 0.], [2.99, 4.]]);
+        assert!(almost_right.is_acute());
+        assert!(!almost_right.is_right(1e-12));
+        assert!(almost_right.is_right(0.01));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let triangle_2d =
+            Triangle::from([[1.0, 0.0], [-1.0, 2.0], [0.0, -3.0]]);
+        let triangle_3d = Triangle::from([
+            [1.0, 0.0, 4.0],
+            [-1.0, 2.0, 0.0],
+            [0.0, -3.0, -2.0],
+        ]);
+
+        assert_eq!(
+            triangle_2d.bounding_box(),
+            Aabb {
+                min: Point::from([-1.0, -3.0]),
+                max: Point::from([1.0, 2.0]),
+            },
+        );
+        assert_eq!(
+            triangle_3d.bounding_box(),
+            Aabb {
+                min: Point::from([-1.0, -3.0, -2.0]),
+                max: Point::from([1.0, 2.0, 4.0]),
+            },
+        );
+    }
+
+    #[test]
+    fn scale_about_center() {
+        let triangle = Triangle::from([[0.0, 0.0], [3.0, 0.0], [3.0, 4.0]]);
+
+        let larger = triangle.scale_about_center(2.);
+        assert_abs_diff_eq!(larger.center(), triangle.center());
+        assert_abs_diff_eq!(
+            larger.edge_lengths().as_slice(),
+            [6.0, 8.0, 10.0].map(Scalar::from).as_slice(),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let unchanged = triangle.scale_about_center(1.);
+        assert_eq!(unchanged, triangle);
+
+        let collapsed = triangle.scale_about_center(0.);
+        assert_eq!(collapsed.points, [triangle.center(); 3]);
+    }
+
+    #[test]
+    fn circumcenter() {
+        let triangle_2d = Triangle::from([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]]);
+        let triangle_3d =
+            Triangle::from([[0.0, 0.0, 1.0], [2.0, 0.0, 1.0], [0.0, 2.0, 1.0]]);
+
+        assert_eq!(triangle_2d.circumcenter(), Some(Point::from([1.0, 1.0])));
+        assert_eq!(
+            triangle_3d.circumcenter(),
+            Some(Point::from([1.0, 1.0, 1.0])),
+        );
+
+        assert_eq!(triangle_2d.circumradius(), Some(Scalar::from(2f64.sqrt())));
+        assert_eq!(triangle_3d.circumradius(), Some(Scalar::from(2f64.sqrt())));
+
+        let degenerate = Triangle::from([[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]);
+        assert_eq!(degenerate.circumcenter(), None);
+        assert_eq!(degenerate.circumradius(), None);
+    }
+
+    #[test]
+    fn incenter() {
+        let triangle = Triangle::from([
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.5, 3f64.sqrt() / 2.0, 0.0],
+        ]);
+
+        assert_abs_diff_eq!(triangle.incenter(), triangle.center());
+        assert_abs_diff_eq!(
+            triangle.inradius(),
+            Scalar::from(3f64.sqrt() / 6.0),
+        );
+    }
+
+    #[test]
+    fn aspect_ratio() {
+        let equilateral =
+            Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.5, 3f64.sqrt() / 2.0]]);
+        let sliver = Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.5, 1e-3]]);
+        let degenerate = Triangle::from([[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]);
+
+        assert_abs_diff_eq!(
+            equilateral.aspect_ratio(),
+            Scalar::ONE,
+            epsilon = Scalar::from(1e-12),
+        );
+        assert!(sliver.aspect_ratio() > Scalar::from(100.));
+        assert_eq!(degenerate.aspect_ratio(), Scalar::MAX);
+    }
+
+    #[test]
+    fn transform() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+
+        let translated =
+            triangle.transform(&Transform::translation([1.0, 2.0, 3.0]));
+        assert_eq!(
+            translated,
+            Triangle::from([[1.0, 2.0, 3.0], [2.0, 2.0, 3.0], [1.0, 3.0, 3.0]]),
+        );
+
+        let rotated = triangle
+            .transform(&Transform::rotation(Vector::unit_x() * Scalar::PI));
+        let expected = Triangle::from([
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, -1.0, 0.0],
+        ]);
+        for (rotated, expected) in
+            rotated.points.into_iter().zip(expected.points)
+        {
+            assert_abs_diff_eq!(
+                rotated,
+                expected,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+        assert_abs_diff_eq!(
+            rotated.normal(),
+            -triangle.normal(),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn flip() {
+        let triangle_2d = Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+        let triangle_3d =
+            Triangle::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+
+        assert_eq!(triangle_2d.winding(), Some(Winding::Ccw));
+        assert_eq!(triangle_2d.flip().winding(), Some(Winding::Cw));
+
+        assert_eq!(triangle_3d.flip().normal(), -triangle_3d.normal());
+    }
+
+    #[test]
+    fn subdivide() {
+        let triangle = Triangle::from([[0.0, 0.0], [3.0, 0.0], [1.0, 2.0]]);
+        let subdivided = triangle.subdivide();
+
+        let area = subdivided
+            .iter()
+            .map(|triangle| triangle.area())
+            .fold(Scalar::ZERO, |a, b| a + b);
+        assert_abs_diff_eq!(area, triangle.area());
+
+        for sub_triangle in subdivided {
+            assert_eq!(sub_triangle.winding(), triangle.winding());
+        }
+    }
+
+    #[test]
+    fn tessellate() {
+        let triangle = Triangle::from([[0.0, 0.0], [3.0, 0.0], [1.0, 2.0]]);
+
+        assert_eq!(triangle.tessellate(0), vec![triangle]);
+        assert_eq!(triangle.tessellate(2).len(), 4);
+
+        for level in 1..=5 {
+            let tessellated = triangle.tessellate(level);
+            assert_eq!(tessellated.len(), level * level);
+
+            let area = tessellated
+                .iter()
+                .map(|triangle| triangle.area())
+                .fold(Scalar::ZERO, |a, b| a + b);
+            assert_abs_diff_eq!(
+                area,
+                triangle.area(),
+                epsilon = Scalar::from(1e-12)
+            );
+
+            for sub_triangle in tessellated {
+                assert_eq!(sub_triangle.winding(), triangle.winding());
+            }
+        }
+    }
+
+    #[test]
+    fn approx_eq() {
+        let a = Triangle::from([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+        let b = Triangle::from([[0.0, 1e-9], [1.0, 0.0], [0.0, 1.0 - 1e-9]]);
+
+        assert!(a.approx_eq(&b, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-12));
+
+        // The order of points is considered.
+        assert!(!a.approx_eq(&b.flip(), 1e-6));
+        assert!(a.normalize().approx_eq(&b.flip().normalize(), 1e-6));
+    }
+
+    #[test]
+    fn normalize_with_tolerance() {
+        let a = Triangle::from([[0., 1.], [0., 0.], [1., 0.]]);
+        let b = Triangle::from([[0., 1.], [1e-12, 0.], [1., 0.]]);
+
+        // Without tolerance, the tiny difference affects the order.
+        assert!(!a.normalize().approx_eq(&b.normalize(), 1e-6));
+
+        let [a, b] = [a, b]
+            .map(|triangle| triangle.flip().normalize_with_tolerance(1e-9));
+        assert_eq!(a.points[0], Point::from([0., 0.]));
+        assert!(a.approx_eq(&b, 1e-6));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let triangle = Triangle::from([
+            [0.0, 0.0, 0.0],
+            [1.5, 0.0, 0.0],
+            [0.0, -2.0, 3.0],
+        ]);
+
+        let json = serde_json::to_string(&triangle).unwrap();
+        assert_eq!(
+            json,
+            r#"{"points":[{"coords":[0.0,0.0,0.0]},{"coords":[1.5,0.0,0.0]},{"coords":[0.0,-2.0,3.0]}]}"#,
+        );
+
+        let deserialized: Triangle<3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, triangle);
+    }
+
+    #[test]
+    fn cast_local_ray_2d() {
+        let triangle = Triangle::from([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]]);
+
+        // hitting an edge
+        assert_eq!(
+            triangle.cast_local_ray(
+                Point::from([1.0, -1.0]),
+                Vector::from([0.0, 1.0]),
+                f64::MAX,
+            ),
+            Some(Scalar::ONE),
+        );
+
+        // missing
+        assert_eq!(
+            triangle.cast_local_ray(
+                Point::from([3.0, -1.0]),
+                Vector::from([0.0, 1.0]),
+                f64::MAX,
+            ),
+            None,
+        );
+
+        // starting inside
+        assert_eq!(
+            triangle.cast_local_ray(
+                Point::from([0.5, 0.5]),
+                Vector::from([0.0, 1.0]),
+                f64::MAX,
+            ),
+            Some(Scalar::ZERO),
+        );
+    }
+
+    #[test]
+    fn intersects() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+
+        let intersecting = Triangle::from([
+            [0.5, 0.5, -1.0],
+            [0.5, 0.5, 1.0],
+            [3.0, 3.0, 0.0],
+        ]);
+        let separate =
+            Triangle::from([[0.0, 0.0, 1.0], [2.0, 0.0, 1.0], [0.0, 2.0, 1.0]]);
+        let shared_edge =
+            Triangle::from([[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [2.0, 2.0, 0.0]]);
+        let shared_edge_hinged =
+            Triangle::from([[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [1.0, 1.0, 1.0]]);
+        let shared_vertex =
+            Triangle::from([[2.0, 0.0, 0.0], [3.0, 0.0, 1.0], [3.0, 1.0, 0.0]]);
+        let coplanar_overlapping =
+            Triangle::from([[0.5, 0.5, 0.0], [3.0, 0.5, 0.0], [0.5, 3.0, 0.0]]);
+        let coplanar_separate =
+            Triangle::from([[3.0, 3.0, 0.0], [4.0, 3.0, 0.0], [3.0, 4.0, 0.0]]);
+
+        assert!(triangle.intersects(&intersecting));
+        assert!(!triangle.intersects(&separate));
+        assert!(triangle.intersects(&shared_edge));
+        assert!(triangle.intersects(&shared_edge_hinged));
+        assert!(triangle.intersects(&shared_vertex));
+        assert!(triangle.intersects(&coplanar_overlapping));
+        assert!(!triangle.intersects(&coplanar_separate));
+    }
+
     #[test]
     fn normal() {
         let triangle =
             Triangle::from([[0.0, 0.0, 0.0], [2.0, 1.0, 0.0], [2.0, 0.0, 0.0]]);
         assert_eq!(triangle.normal(), Vector::from([0.0, 0.0, -1.0]));
+
+        let triangles = [
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.]],
+            [[1., 2., 3.], [-2., 0.5, 1.], [0., -1., 4.]],
+            [[0., 0., 5.], [3., 0., 1.], [3., 4., 0.]],
+        ]
+        .map(Triangle::from);
+        for triangle in triangles {
+            let expected: Vector<3> =
+                triangle.to_parry().normal().unwrap().into_inner().into();
+            assert_abs_diff_eq!(
+                triangle.normal(),
+                expected,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn orientation_of() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+
+        // Too close to the plane for a naive computation to be reliable, but
+        // the robust predicate still gets it right.
+        let above = [0.25, 0.25, 1e-300];
+        let below = [0.25, 0.25, -1e-300];
+        let on = [0.25, 0.25, 0.];
+
+        assert_eq!(triangle.orientation_of(above), Some(Winding::Ccw));
+        assert_eq!(triangle.orientation_of(below), Some(Winding::Cw));
+        assert_eq!(triangle.orientation_of(on), None);
+
+        let tilted = Triangle::from([[0., 0., 0.], [1., 0., 1.], [0., 1., 1.]]);
+        let on_tilted = [0.25, 0.5, 0.75];
+        let above_tilted = [0.25, 0.5, 0.75 + 1e-15];
+        assert_eq!(tilted.orientation_of(on_tilted), None);
+        assert_eq!(tilted.orientation_of(above_tilted), Some(Winding::Ccw));
+    }
+
+    #[test]
+    fn winding_about() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+
+        assert_eq!(triangle.winding_about([0., 0., 1.]), Some(Winding::Ccw));
+        assert_eq!(triangle.winding_about([1., 1., 1.]), Some(Winding::Ccw));
+        assert_eq!(triangle.winding_about([0., 0., -1.]), Some(Winding::Cw));
+        assert_eq!(
+            triangle.flip().winding_about([0., 0., 1.]),
+            Some(Winding::Cw)
+        );
+
+        let degenerate =
+            Triangle::from([[0., 0., 0.], [1., 0., 0.], [2., 0., 0.]]);
+        assert_eq!(degenerate.winding_about([0., 0., 1.]), None);
+    }
+
+    #[test]
+    fn signed_distance() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+
+        let above = [0.25, 0.25, 2.];
+        let below = [0.25, 0.25, -2.];
+        assert_eq!(triangle.signed_distance(above), Scalar::from(2.));
+        assert_eq!(triangle.signed_distance(below), Scalar::from(-2.));
+
+        // Off to the side, the closest point is on an edge or a vertex, but the
+        // sign is still determined by the plane.
+        let above_beside_edge = [0.5, -3., 4.];
+        let below_beside_vertex = [-3., -4., -12.];
+        assert_eq!(
+            triangle.signed_distance(above_beside_edge),
+            Scalar::from(5.)
+        );
+        assert_eq!(
+            triangle.signed_distance(below_beside_vertex),
+            Scalar::from(-13.)
+        );
+
+        let on_plane_beside_vertex = [-3., -4., 0.];
+        assert_eq!(
+            triangle.signed_distance(on_plane_beside_vertex),
+            Scalar::from(5.)
+        );
+    }
+
+    #[test]
+    fn interpolate() {
+        let triangle = Triangle::from([[0., 0.], [3., 0.], [0., 3.]]);
+
+        let weights = triangle.point_to_barycentric_coords(triangle.center());
+        let values = [1., 2., 6.].map(Scalar::from);
+        assert_abs_diff_eq!(
+            triangle.interpolate(weights, values),
+            Scalar::from(3.),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let weights = [1., 0., 0.].map(Scalar::from);
+        let values = [[1., 0.], [0., 1.], [1., 1.]].map(Vector::from);
+        assert_eq!(
+            triangle.interpolate(weights, values),
+            Vector::from([1., 0.])
+        );
+    }
+
+    #[test]
+    fn to_xy_and_drop_z() {
+        let triangle = Triangle::from([[0., 0.], [2., 1.], [-1., 3.]]);
+        let lifted = triangle.to_xy();
+
+        assert_eq!(
+            lifted,
+            Triangle::from([[0., 0., 0.], [2., 1., 0.], [-1., 3., 0.]])
+        );
+        assert_eq!(lifted.drop_z(), triangle);
+
+        let not_in_plane =
+            Triangle::from([[0., 0., 1.], [2., 1., 2.], [-1., 3., 3.]]);
+        assert_eq!(not_in_plane.drop_z(), triangle);
+    }
+
+    #[test]
+    fn winding_sign_and_reverse() {
+        for winding in [Winding::Ccw, Winding::Cw] {
+            assert_eq!(winding.reverse().reverse(), winding);
+            assert_ne!(winding.reverse(), winding);
+            assert_eq!(winding.reverse().sign(), -winding.sign());
+        }
+
+        let ccw = Triangle::from([[0., 0.], [2., 0.], [0., 2.]]);
+        let cw = Triangle::from([[0., 0.], [0., 2.], [2., 0.]]);
+        for triangle in [ccw, cw] {
+            let winding = triangle.winding().unwrap();
+            assert_eq!(
+                triangle.area() * winding.sign(),
+                triangle.signed_area()
+            );
+        }
+    }
+
+    #[test]
+    fn quad_to_triangles() {
+        // A kite-shaped quad, with the diagonal from `b` to `d` being the
+        // shorter one.
+        let corners =
+            [[0., 0.], [2., -1.], [4., 0.], [2., 1.]].map(Point::from);
+        let [a, b, c, d] = corners;
+
+        let triangles = super::quad_to_triangles(corners);
+        assert_eq!(
+            triangles,
+            [[a, b, d], [b, c, d]].map(|points| Triangle { points })
+        );
+
+        // Same quad, but starting at another corner. Now the shorter diagonal
+        // goes from `a` to `c`.
+        let triangles = super::quad_to_triangles([b, c, d, a]);
+        assert_eq!(
+            triangles,
+            [[b, c, d], [b, d, a]].map(|points| Triangle { points })
+        );
+
+        for triangle in triangles {
+            assert_eq!(triangle.winding(), Some(Winding::Ccw));
+        }
+    }
+
+    #[test]
+    fn fan() {
+        let center = Point::from([0., 0.]);
+        let ring = [[1., 0.], [0., 1.], [-1., 0.], [0., -1.]].map(Point::from);
+
+        let closed = super::fan(center, &ring, true);
+        assert_eq!(closed.len(), 4);
+        for triangle in &closed {
+            assert_eq!(triangle.points[0], center);
+            assert_eq!(triangle.winding(), Some(Winding::Ccw));
+        }
+        assert_eq!(closed[3].points, [center, ring[3], ring[0]]);
+
+        let area = closed
+            .iter()
+            .map(|triangle| triangle.area())
+            .fold(Scalar::ZERO, |a, b| a + b);
+        assert_eq!(area, Scalar::TWO);
+
+        let open = super::fan(center, &ring, false);
+        assert_eq!(open[..], closed[..3]);
+
+        assert!(super::fan(center, &ring[..1], true).is_empty());
+        assert_eq!(super::fan(center, &ring[..2], true).len(), 1);
     }
 }
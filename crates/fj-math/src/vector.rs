@@ -1,5 +1,6 @@
 use std::{fmt, ops};
 
+use approx::AbsDiffEq;
 use iter_fixed::IntoIteratorFixed;
 
 use crate::Bivector;
@@ -85,19 +86,55 @@ impl<const D: usize> Vector<D> {
     }
 
     /// # Compute a normalized version of the vector
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the vector has zero magnitude. Use
+    /// [`Vector::normalize_checked`], if that can happen.
     pub fn normalize(&self) -> Self {
         *self / self.magnitude()
     }
 
+    /// # Compute a normalized version of the vector, if possible
+    ///
+    /// Returns `None`, if the magnitude of the vector is not larger than
+    /// [`Scalar`]'s default epsilon value.
+    pub fn normalize_checked(&self) -> Option<Self> {
+        let magnitude = self.magnitude();
+
+        if magnitude <= Scalar::default_epsilon() {
+            return None;
+        }
+
+        Some(*self / magnitude)
+    }
+
+    /// # Determine whether all components of the vector are finite
+    ///
+    /// This always returns `true`. [`Scalar`] rejects NaN and infinite values
+    /// on construction, so a `Vector` can't contain them. The method exists
+    /// for code that wants to validate its input explicitly, for example right
+    /// after importing geometry from a file.
+    pub fn is_finite(&self) -> bool {
+        self.components
+            .iter()
+            .all(|component| component.into_f64().is_finite())
+    }
+
     /// # Compute the angle between this vector and another
     ///
-    /// Returns a zero angle, if the magnitude of `self` or `other` is zero.
+    /// Returns the unsigned angle in radians, in the range `0..=π`. Returns a
+    /// zero angle, if the magnitude of `self` or `other` is zero.
     pub fn angle_to(&self, other: &Self) -> Scalar {
         let product = self.magnitude() * other.magnitude();
         if product.is_zero() {
             Scalar::ZERO
         } else {
-            (self.dot(other) / product).acos()
+            // Due to floating point error, the cosine can end up slightly
+            // outside of its valid range for (anti-)parallel vectors.
+            (self.dot(other) / product)
+                .clamp(-Scalar::ONE, Scalar::ONE)
+                .acos()
         }
     }
 
@@ -111,6 +148,32 @@ impl<const D: usize> Vector<D> {
             .unwrap_or(Scalar::ZERO)
     }
 
+    /// # Compute the component-wise minimum of this vector and another
+    ///
+    /// Each component of the result is the smaller of the respective
+    /// components of both vectors. This is different from [`Ord::min`], which
+    /// compares the vectors lexicographically and returns one of them.
+    pub fn component_min(self, other: impl Into<Self>) -> Self {
+        let other = other.into();
+        let components = std::array::from_fn(|i| {
+            self.components[i].min(other.components[i])
+        });
+        Self { components }
+    }
+
+    /// # Compute the component-wise maximum of this vector and another
+    ///
+    /// Each component of the result is the larger of the respective components
+    /// of both vectors. This is different from [`Ord::max`], which compares
+    /// the vectors lexicographically and returns one of them.
+    pub fn component_max(self, other: impl Into<Self>) -> Self {
+        let other = other.into();
+        let components = std::array::from_fn(|i| {
+            self.components[i].max(other.components[i])
+        });
+        Self { components }
+    }
+
     /// # Compute the outer product with another vector
     pub fn outer(&self, other: &Self) -> Bivector<D> {
         Bivector {
@@ -127,6 +190,29 @@ impl<const D: usize> Vector<D> {
 
         self.dot(&other.normalize())
     }
+
+    /// # Compute the vector projection of this vector onto another
+    ///
+    /// Returns the component of this vector that is parallel to `other`, or a
+    /// zero vector, if the magnitude of `other` is zero.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let other_dot_other = other.dot(other);
+        if other_dot_other.is_zero() {
+            return Self::from([Scalar::ZERO; D]);
+        }
+
+        *other * (self.dot(other) / other_dot_other)
+    }
+
+    /// # Compute the vector rejection of this vector from another
+    ///
+    /// Returns the component of this vector that is perpendicular to `other`.
+    /// This is the vector minus its [projection](Self::project_onto) onto
+    /// `other`, so if the magnitude of `other` is zero, the vector is returned
+    /// unchanged.
+    pub fn reject_from(&self, other: &Self) -> Self {
+        *self - self.project_onto(other)
+    }
 }
 
 impl Vector<1> {
@@ -414,8 +500,95 @@ impl<const D: usize> approx::AbsDiffEq for Vector<D> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<const D: usize> serde::Serialize for Vector<D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple as _;
+
+        // `serde` doesn't support arrays of arbitrary length, so we have to
+        // serialize the components as a tuple manually.
+        let mut tuple = serializer.serialize_tuple(D)?;
+        for component in &self.components {
+            tuple.serialize_element(component)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const D: usize> serde::Deserialize<'de> for Vector<D> {
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        struct Visitor<const D: usize>;
+
+        impl<'de, const D: usize> serde::de::Visitor<'de> for Visitor<D> {
+            type Value = Vector<D>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of {D} scalars")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut components = [Scalar::ZERO; D];
+
+                for (i, component) in components.iter_mut().enumerate() {
+                    *component = seq.next_element()?.ok_or_else(|| {
+                        serde::de::Error::invalid_length(i, &self)
+                    })?;
+                }
+
+                Ok(Vector { components })
+            }
+        }
+
+        deserializer.deserialize_tuple(D, Visitor)
+    }
+}
+
+/// # Compute two unit vectors that are perpendicular to a normal
+///
+/// The returned vectors `[u, v]` are perpendicular to `normal` and to each
+/// other, and they are oriented such that `u × v` points in the direction of
+/// `normal`. Together with the normalized normal, they form a right-handed
+/// orthonormal basis.
+///
+/// `u` is derived from the coordinate axis that is least aligned with `normal`,
+/// using Gram-Schmidt. This keeps the computation stable for any direction,
+/// including ones that are on or near a coordinate axis.
+///
+/// ## Panics
+///
+/// Panics, if `normal` has zero magnitude.
+pub fn orthonormal_basis(normal: impl Into<Vector<3>>) -> [Vector<3>; 2] {
+    let normal = normal.into().normalize();
+
+    let [x, y, z] = normal.components.map(|s| s.abs());
+    let axis = if x <= y && x <= z {
+        Vector::unit_x()
+    } else if y <= z {
+        Vector::unit_y()
+    } else {
+        Vector::unit_z()
+    };
+
+    let u = axis.reject_from(&normal).normalize();
+    let v = normal.cross(&u);
+
+    [u, v]
+}
+
 #[cfg(test)]
 mod tests {
+    use approx::assert_abs_diff_eq;
+
     use crate::{Scalar, Vector};
 
     #[test]
@@ -439,6 +612,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn normalize_checked() {
+        let unit = Vector::from([0., 1., 0.]);
+        assert_eq!(unit.normalize_checked(), Some(unit));
+
+        assert_eq!(
+            Vector::from([3., 0., 4.]).normalize_checked(),
+            Some(Vector::from([0.6, 0., 0.8])),
+        );
+
+        assert_eq!(Vector::from([0., 0., 0.]).normalize_checked(), None);
+        assert_eq!(Vector::from([1e-20, 0., 0.]).normalize_checked(), None);
+    }
+
+    #[test]
+    fn is_finite() {
+        assert!(Vector::from([0., -1., f64::MAX]).is_finite());
+        assert!(Vector::from([f64::MIN_POSITIVE, 1e300]).is_finite());
+    }
+
+    #[test]
+    fn component_min_max() {
+        let a = Vector::from([-1., 2., -3.]);
+        let b = Vector::from([1., -2., -4.]);
+
+        assert_eq!(a.component_min(b), Vector::from([-1., -2., -4.]));
+        assert_eq!(a.component_max(b), Vector::from([1., 2., -3.]));
+        assert_eq!(b.component_min(a), a.component_min(b));
+        assert_eq!(b.component_max(a), a.component_max(b));
+    }
+
     #[test]
     fn scalar_projection_onto() {
         let v = Vector::from([1., 2., 3.]);
@@ -459,6 +663,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn project_onto() {
+        let v = Vector::from([1., 2., 3.]);
+
+        let x = Vector::unit_x() * 3.;
+        let y = Vector::unit_y() * -2.;
+        let xy = Vector::from([1., 1., 0.]);
+
+        assert_eq!(v.project_onto(&x), Vector::from([1., 0., 0.]));
+        assert_eq!(v.project_onto(&y), Vector::from([0., 2., 0.]));
+        assert_eq!(v.project_onto(&xy), Vector::from([1.5, 1.5, 0.]));
+
+        // Zero-length vectors should be handled as well.
+        assert_eq!(
+            v.project_onto(&Vector::from([0., 0., 0.])),
+            Vector::from([0., 0., 0.]),
+        );
+    }
+
+    #[test]
+    fn reject_from() {
+        let v = Vector::from([1., 2., 3.]);
+
+        let x = Vector::unit_x() * 3.;
+        let diagonal = Vector::from([1., -1., 2.]);
+
+        assert_eq!(v.reject_from(&x), Vector::from([0., 2., 3.]));
+
+        let rejection = v.reject_from(&diagonal);
+        assert_abs_diff_eq!(
+            rejection.dot(&diagonal),
+            Scalar::ZERO,
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(rejection + v.project_onto(&diagonal), v);
+
+        // Zero-length vectors should be handled as well.
+        assert_eq!(v.reject_from(&Vector::from([0., 0., 0.])), v);
+    }
+
+    #[test]
+    fn orthonormal_basis() {
+        let normals = [
+            [1., 0., 0.],
+            [0., 1., 0.],
+            [0., 0., -1.],
+            [1., 1., 1.],
+            [-0.3, 2., 0.5],
+            [1., 1e-9, -1e-9],
+        ];
+
+        for normal in normals.map(Vector::from) {
+            let [u, v] = super::orthonormal_basis(normal);
+
+            let epsilon = Scalar::from(1e-12);
+            assert_abs_diff_eq!(u.magnitude(), Scalar::ONE, epsilon = epsilon);
+            assert_abs_diff_eq!(v.magnitude(), Scalar::ONE, epsilon = epsilon);
+            assert_abs_diff_eq!(u.dot(&v), Scalar::ZERO, epsilon = epsilon);
+            assert_abs_diff_eq!(
+                u.dot(&normal),
+                Scalar::ZERO,
+                epsilon = epsilon
+            );
+            assert_abs_diff_eq!(
+                v.dot(&normal),
+                Scalar::ZERO,
+                epsilon = epsilon
+            );
+            assert_abs_diff_eq!(
+                u.cross(&v),
+                normal.normalize(),
+                epsilon = epsilon,
+            );
+        }
+    }
+
+    #[test]
+    fn angle_to() {
+        let v = Vector::from([0.1, 0.7]);
+
+        assert_eq!(v.angle_to(&Vector::from([-0.7, 0.1])), Scalar::PI / 2.);
+        assert_eq!(v.angle_to(&(v * 3.)), Scalar::ZERO);
+        assert_eq!(v.angle_to(&(v * -3.)), Scalar::PI);
+
+        // Zero-length vectors should be handled as well.
+        assert_eq!(v.angle_to(&Vector::from([0., 0.])), Scalar::ZERO);
+    }
+
     #[test]
     fn is_between() {
         let v = Vector::from([1., 1.]);
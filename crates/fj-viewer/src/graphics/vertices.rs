@@ -29,14 +29,20 @@ impl Vertices {
 
     pub fn for_mesh(tri_mesh: &TriMesh) -> Self {
         let (vertices, indices) = vertices_to_indexed_vertices(
-            tri_mesh.triangles.iter().flat_map(|triangle| {
-                let [a, b, c] = triangle.inner.points;
+            tri_mesh
+                .triangles
+                .iter()
+                .filter_map(|triangle| {
+                    let [a, b, c] = triangle.inner.points;
 
-                let normal = (b - a).cross(&(c - a)).normalize();
-                let color = triangle.color;
+                    // Degenerate triangles have no normal. They wouldn't be
+                    // visible anyway, so we can just skip them.
+                    let normal = (b - a).cross(&(c - a)).normalize_checked()?;
+                    let color = triangle.color;
 
-                [a, b, c].map(|point| (point, normal, color))
-            }),
+                    Some([a, b, c].map(|point| (point, normal, color)))
+                })
+                .flatten(),
             |(point, normal, color)| Vertex {
                 position: point.into(),
                 normal: normal.into(),
@@ -111,6 +111,12 @@ impl ApplicationHandler for App {
                     Key::Named(NamedKey::ArrowUp) => {
                         self.view.parent_of_selected_mut().select_previous();
                     }
+                    Key::Character(character) if character == "s" => {
+                        renderer.smooth_shading = !renderer.smooth_shading;
+                    }
+                    Key::Character(character) if character == "w" => {
+                        renderer.wireframe = !renderer.wireframe;
+                    }
                     _ => {}
                 }
 
@@ -1,11 +1,11 @@
-//! # Exporting geometry to 3MF
+//! # Exporting geometry to 3MF and PLY
 //!
-//! Nothing interesting to see here! This is just a thin layer on top of
-//! [3mf-rs](threemf).
+//! Nothing interesting to see here! The 3MF export is just a thin layer on top
+//! of [3mf-rs](threemf). PLY is simple enough to write by hand.
 
-use std::{collections::BTreeMap, fs::File};
+use std::{collections::BTreeMap, fs::File, io};
 
-use crate::object::Object;
+use crate::{geometry::TriMesh, math::Vector, object::Object};
 
 pub fn export(op: &dyn Object) -> anyhow::Result<()> {
     let tri_mesh = op.tri_mesh();
@@ -46,5 +46,140 @@ pub fn export(op: &dyn Object) -> anyhow::Result<()> {
     let output = File::create("output.3mf")?;
     threemf::write(output, mesh)?;
 
+    let output = File::create("output.ply")?;
+    write_ply(&tri_mesh, io::BufWriter::new(output))?;
+
+    Ok(())
+}
+
+/// # Write the external triangles of a mesh as ASCII PLY
+///
+/// Vertices are shared between all triangles that have the same position, and
+/// each vertex gets the area-weighted average normal of those triangles. This
+/// makes the result look smooth, when rendered by other tools.
+///
+/// Degenerate triangles (see [`Triangle::is_valid`]) are skipped, like they are
+/// when rendering. They don't have a meaningful normal, and a vertex that only
+/// belongs to degenerate triangles would end up without one.
+///
+/// [`Triangle::is_valid`]: crate::geometry::Triangle::is_valid
+pub fn write_ply(
+    tri_mesh: &TriMesh,
+    mut writer: impl io::Write,
+) -> io::Result<()> {
+    let mut indices_by_vertex = BTreeMap::new();
+
+    let mut points = Vec::new();
+    let mut normals = Vec::new();
+    let mut faces = Vec::new();
+
+    let triangles = tri_mesh
+        .triangles
+        .iter()
+        .zip(tri_mesh.face_normals())
+        .filter(|(triangle, _)| {
+            !triangle.is_internal && triangle.inner.is_valid()
+        });
+
+    for (triangle, normal) in triangles {
+        let face = triangle.inner.points.map(|point| {
+            let index = *indices_by_vertex.entry(point).or_insert_with(|| {
+                let index = points.len();
+                points.push(point);
+                normals.push(Vector::from([0., 0., 0.]));
+                index
+            });
+
+            // The length of the face normal is proportional to the triangle's
+            // area, so just summing them up results in a weighted average.
+            normals[index] = normals[index] + normal;
+
+            index
+        });
+
+        faces.push(face);
+    }
+
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format ascii 1.0")?;
+    writeln!(writer, "element vertex {}", points.len())?;
+    for property in ["x", "y", "z", "nx", "ny", "nz"] {
+        writeln!(writer, "property float {property}")?;
+    }
+    writeln!(writer, "element face {}", faces.len())?;
+    writeln!(writer, "property list uchar uint vertex_indices")?;
+    writeln!(writer, "end_header")?;
+
+    for (point, normal) in points.into_iter().zip(normals) {
+        let [x, y, z] = point.coords.components.map(|s| s.value());
+        let [nx, ny, nz] = normal.normalize().components.map(|s| s.value());
+        writeln!(writer, "{x} {y} {z} {nx} {ny} {nz}")?;
+    }
+    for [a, b, c] in faces {
+        writeln!(writer, "3 {a} {b} {c}")?;
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{MeshTriangle, TriMesh, Triangle};
+
+    use super::write_ply;
+
+    #[test]
+    fn write_ply_quad() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+
+        let tri_mesh = TriMesh {
+            triangles: [[a, b, c], [a, c, d]]
+                .into_iter()
+                .map(|points| MeshTriangle {
+                    inner: Triangle::from(points),
+                    is_internal: false,
+                })
+                .collect(),
+        };
+
+        let mut ply = Vec::new();
+        write_ply(&tri_mesh, &mut ply).unwrap();
+        let ply = String::from_utf8(ply).unwrap();
+
+        let (header, body) = ply.split_once("end_header\n").unwrap();
+        assert!(header.contains("element vertex 4\n"));
+        assert!(header.contains("element face 2\n"));
+
+        let lines = body.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4 + 2);
+        assert!(lines[..4].iter().all(|line| line.ends_with(" 0 0 1")));
+        assert_eq!(lines[4..], ["3 0 1 2", "3 0 2 3"]);
+    }
+
+    #[test]
+    fn write_ply_degenerate_triangle() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.], [0., 1., 0.]];
+        let [e, f] = [[2., 2., 0.], [3., 3., 0.]];
+
+        let tri_mesh = TriMesh {
+            triangles: [[a, b, c], [a, c, d], [c, e, f]]
+                .into_iter()
+                .map(|points| MeshTriangle {
+                    inner: Triangle::from(points),
+                    is_internal: false,
+                })
+                .collect(),
+        };
+
+        let mut ply = Vec::new();
+        write_ply(&tri_mesh, &mut ply).unwrap();
+        let ply = String::from_utf8(ply).unwrap();
+
+        let (header, body) = ply.split_once("end_header\n").unwrap();
+        assert!(header.contains("element vertex 4\n"));
+        assert!(header.contains("element face 2\n"));
+        assert!(!body.contains("NaN"));
+    }
+}
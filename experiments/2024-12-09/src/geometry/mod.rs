@@ -1,5 +1,5 @@
 //! # Various geometry tools
-//! 
+//!
 //! These are distinct from the core b-rep representation, for which this module
 //! is a dependency.
 
@@ -8,6 +8,9 @@ mod surface;
 mod tri_mesh;
 mod triangle;
 
+#[cfg(test)]
+pub mod test_helpers;
+
 pub use self::{
     sketch::Sketch,
     surface::SurfaceGeometry,
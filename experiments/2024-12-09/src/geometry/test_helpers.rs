@@ -0,0 +1,24 @@
+use super::{MeshTriangle, TriMesh, Triangle};
+
+/// # Create a triangle mesh with many triangles
+///
+/// Triangle `i` has a right angle at its first point, with legs of length `1`
+/// and `sqrt(i² + 1)`. None of the normals are axis-aligned, except the first
+/// one, and no two triangles have the same area.
+pub fn many_triangles(num_triangles: u32) -> TriMesh {
+    TriMesh {
+        triangles: (0..num_triangles)
+            .map(|i| {
+                let x = f64::from(i);
+                MeshTriangle {
+                    inner: Triangle::from([
+                        [x, 0., 0.],
+                        [x + 1., 0., 0.],
+                        [x, x, 1.],
+                    ]),
+                    is_internal: false,
+                }
+            })
+            .collect(),
+    }
+}
@@ -1,3 +1,5 @@
+use crate::math::{Point, Vector};
+
 use super::Triangle;
 
 /// # A triangle mesh
@@ -36,6 +38,36 @@ impl TriMesh {
         self.triangles.iter().map(|triangle| triangle.inner)
     }
 
+    /// # Compute the normal of every triangle in the mesh
+    ///
+    /// The normals are returned in the same order as the triangles. They are
+    /// not normalized. Instead, the length of each normal is twice the area of
+    /// its triangle, which makes them suitable for computing area-weighted
+    /// averages.
+    pub fn face_normals(&self) -> Vec<Vector<3>> {
+        self.all_triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.points;
+                (b - a).cross(c - a)
+            })
+            .collect()
+    }
+
+    /// # Iterate over all the triangles in the mesh, in 2D
+    ///
+    /// This is intended for meshes that are known to be flat and to lie in the
+    /// xy-plane, like those of sketches. The z coordinate of every point is
+    /// discarded.
+    #[allow(unused)] // useful for 2D sketches
+    pub fn all_triangles_2d(&self) -> impl Iterator<Item = Triangle<2>> {
+        self.all_triangles().map(|triangle| Triangle {
+            points: triangle.map(|point| {
+                let [x, y, _] = point.coords.components;
+                Point::from([x, y])
+            }),
+        })
+    }
+
     /// # Iterate over the triangles in the mesh that are not marked internal
     ///
     /// See [`MeshTriangle`] for an explanation of internal and external
@@ -64,3 +96,29 @@ pub struct MeshTriangle {
     pub inner: Triangle<3>,
     pub is_internal: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::test_helpers::many_triangles;
+
+    #[test]
+    fn face_normals() {
+        let num_triangles = 10_000;
+        let tri_mesh = many_triangles(num_triangles);
+
+        let face_normals = tri_mesh.face_normals();
+        assert_eq!(face_normals.len(), num_triangles as usize);
+
+        for (triangle, normal) in tri_mesh.all_triangles().zip(face_normals) {
+            let [a, b, c] = triangle.points;
+            let [ab, ac] = [b - a, c - a];
+
+            // The triangle has a right angle at `a`.
+            let area = ab.magnitude() * ac.magnitude() / 2.;
+
+            assert!((normal.magnitude() - area * 2.).value().abs() < 1e-9);
+            assert!(normal.dot(&ab).value().abs() < 1e-9);
+            assert!(normal.dot(&ac).value().abs() < 1e-9);
+        }
+    }
+}
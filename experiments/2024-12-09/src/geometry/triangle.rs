@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::{
-    math::Point,
+    math::{Plane, Point},
     object::{HandleAny, Object},
 };
 
@@ -22,6 +22,43 @@ impl<const D: usize> Triangle<D> {
         let coords = (a.coords + b.coords + c.coords) / 3.;
         Point { coords }
     }
+
+    /// # Apply a function to each point of the triangle
+    pub fn map<Q>(&self, f: impl Fn(Point<D>) -> Q) -> [Q; 3] {
+        self.points.map(f)
+    }
+}
+
+impl Triangle<3> {
+    /// # Determine whether the triangle is valid
+    ///
+    /// A triangle is valid, if its points are not collinear. To also reject
+    /// slivers that are almost collinear, the area of the triangle is compared
+    /// to the square of its longest edge, so the result doesn't depend on the
+    /// scale of the triangle.
+    pub fn is_valid(&self) -> bool {
+        const EPSILON: f64 = 1e-9;
+
+        let [a, b, c] = self.points;
+
+        let twice_the_area = (b - a).cross(c - a).magnitude().value();
+        let longest_edge_squared = [b - a, c - b, a - c]
+            .map(|edge| edge.dot(&edge).value())
+            .into_iter()
+            .fold(0., f64::max);
+
+        twice_the_area > longest_edge_squared * EPSILON
+    }
+
+    /// # Compute the plane that the triangle lies in
+    ///
+    /// The plane's origin is the triangle's first point. Its axes are the
+    /// edges from there to the other two points, so the triangle's points are
+    /// at `[0, 0]`, `[1, 0]`, and `[0, 1]` in the plane's local coordinates.
+    #[allow(unused)] // useful for projecting points into a triangle's plane
+    pub fn plane(&self) -> Plane {
+        Plane::from_points(self.points)
+    }
 }
 
 impl<P, const D: usize> From<[P; 3]> for Triangle<D>
@@ -53,3 +90,57 @@ impl Object for Triangle<3> {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Triangle;
+
+    #[test]
+    fn map() {
+        let triangle =
+            Triangle::from([[1., 2., 3.], [-2., 0.5, 1.], [0., -1., 4.]]);
+
+        assert_eq!(triangle.map(|point| point), triangle.points);
+        assert_eq!(
+            triangle.map(|point| point.coords.components[2].value()),
+            [3., 1., 4.],
+        );
+    }
+
+    #[test]
+    fn is_valid() {
+        let valid = Triangle::from([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+        let collinear =
+            Triangle::from([[0., 0., 0.], [1., 1., 0.], [2., 2., 0.]]);
+        let sliver =
+            Triangle::from([[0., 0., 0.], [1., 0., 0.], [2., 1e-12, 0.]]);
+        let coincident =
+            Triangle::from([[1., 2., 3.], [1., 2., 3.], [1., 2., 3.]]);
+
+        assert!(valid.is_valid());
+        assert!(!collinear.is_valid());
+        assert!(!sliver.is_valid());
+        assert!(!coincident.is_valid());
+
+        let scaled_down =
+            Triangle::from([[0., 0., 0.], [1e-6, 0., 0.], [0., 1e-6, 0.]]);
+        assert!(scaled_down.is_valid());
+    }
+
+    #[test]
+    fn plane() {
+        let triangle =
+            Triangle::from([[1., 2., 3.], [-2., 0.5, 1.], [0., -1., 4.]]);
+        let plane = triangle.plane();
+
+        for point in triangle.points {
+            let signed_distance = (point - plane.origin).dot(&plane.normal());
+            assert!(signed_distance.value().abs() < 1e-12);
+        }
+
+        let locals = [[0., 0.], [1., 0.], [0., 1.]];
+        for (point, local) in triangle.points.into_iter().zip(locals) {
+            assert_eq!(plane.point_from_local(local), point);
+        }
+    }
+}
@@ -1,50 +1,130 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use glam::Vec3;
 use wgpu::util::DeviceExt;
 
-use crate::object::Object;
+use crate::{
+    geometry::{MeshTriangle, TriMesh, Triangle},
+    math::{Point, Scalar, Vector},
+    object::Object,
+};
 
 use super::vertex::Vertex;
 
 pub struct Geometry {
     pub vertices: wgpu::Buffer,
     pub indices: wgpu::Buffer,
+
+    #[allow(unused)] // useful for checking the effect of vertex deduplication
+    pub num_vertices: u32,
+
     pub num_indices: u32,
+    pub topology: wgpu::PrimitiveTopology,
 }
 
 impl Geometry {
+    /// # Create geometry with flat shading
+    ///
+    /// See [`Shading::Flat`].
     pub fn new(device: &wgpu::Device, operation: &dyn Object) -> Self {
-        let tri_mesh = operation.tri_mesh();
+        let GeometryData { vertices, indices } =
+            GeometryData::from_object(operation);
 
-        let mut indices = Vec::new();
-        let mut vertices = Vec::new();
+        Self::from_vertices_and_indices(
+            device,
+            &vertices,
+            &indices,
+            wgpu::PrimitiveTopology::TriangleList,
+        )
+    }
 
-        for triangle in tri_mesh.all_triangles() {
-            let triangle = triangle.points.each_ref().map(|point| {
-                Vec3::from(
-                    point.coords.components.map(|coord| coord.value() as f32),
-                )
-            });
-            let normal = {
-                let [a, b, c] = triangle;
+    /// # Create geometry with flat shading and custom vertex colors
+    ///
+    /// Calls `color` with the position of each vertex, to determine the color
+    /// of that vertex. This can be used to visualize a field of values over
+    /// the surface of a shape.
+    #[allow(unused)] // useful for visualizing values over a surface
+    pub fn with_vertex_colors(
+        device: &wgpu::Device,
+        operation: &dyn Object,
+        color: &dyn Fn(Vec3) -> [f32; 3],
+    ) -> Self {
+        Self::with_shading(device, operation, Shading::Flat, color)
+    }
 
-                let ab = b - a;
-                let ac = c - a;
+    /// # Create geometry from 2D triangles
+    ///
+    /// The triangles are placed in the xy-plane, at z=0.
+    #[allow(unused)] // useful for 2D sketches
+    pub fn from_triangles_2d(
+        device: &wgpu::Device,
+        triangles: impl IntoIterator<Item = Triangle<2>>,
+    ) -> Self {
+        let (vertices, indices) =
+            vertices_and_indices(&lift_to_3d(triangles), Shading::Flat, &white);
 
-                ab.cross(ac)
-            };
+        Self::from_vertices_and_indices(
+            device,
+            &vertices,
+            &indices,
+            wgpu::PrimitiveTopology::TriangleList,
+        )
+    }
 
-            for point in triangle {
-                let index = vertices.len() as u32;
-                let vertex = Vertex {
-                    position: point.into(),
-                    normal: normal.into(),
-                };
+    /// # Create geometry with smooth shading
+    ///
+    /// See [`Shading::Smooth`].
+    pub fn new_smooth(device: &wgpu::Device, operation: &dyn Object) -> Self {
+        Self::with_shading(device, operation, Shading::Smooth, &white)
+    }
 
-                indices.push(index);
-                vertices.push(vertex);
-            }
-        }
+    /// # Create geometry that renders the edges of the triangles
+    ///
+    /// Each edge is only included once, even if it is shared between multiple
+    /// triangles.
+    pub fn new_wireframe(
+        device: &wgpu::Device,
+        operation: &dyn Object,
+    ) -> Self {
+        let tri_mesh = operation.tri_mesh();
+        let (vertices, indices) =
+            vertices_and_indices(&tri_mesh, Shading::Smooth, &white);
+        let indices = edge_indices(&indices);
+
+        Self::from_vertices_and_indices(
+            device,
+            &vertices,
+            &indices,
+            wgpu::PrimitiveTopology::LineList,
+        )
+    }
+
+    fn with_shading(
+        device: &wgpu::Device,
+        operation: &dyn Object,
+        shading: Shading,
+        color: &dyn Fn(Vec3) -> [f32; 3],
+    ) -> Self {
+        let GeometryData { vertices, indices } =
+            GeometryData::with_shading(operation, shading, color);
+
+        Self::from_vertices_and_indices(
+            device,
+            &vertices,
+            &indices,
+            wgpu::PrimitiveTopology::TriangleList,
+        )
+    }
 
+    fn from_vertices_and_indices(
+        device: &wgpu::Device,
+        vertices: &[Vertex],
+        indices: &[u32],
+        topology: wgpu::PrimitiveTopology,
+    ) -> Self {
+        let Ok(num_vertices) = vertices.len().try_into() else {
+            panic!("Unsupported number of vertices: `{}`", vertices.len());
+        };
         let Ok(num_indices) = indices.len().try_into() else {
             panic!("Unsupported number of indices: `{}`", indices.len());
         };
@@ -52,20 +132,518 @@ impl Geometry {
         let vertices =
             device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
                 label: None,
-                contents: bytemuck::cast_slice(&vertices),
+                contents: buffer_contents(bytemuck::cast_slice(vertices)),
                 usage: wgpu::BufferUsages::VERTEX,
             });
         let indices =
             device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
                 label: None,
-                contents: bytemuck::cast_slice(&indices),
+                contents: buffer_contents(bytemuck::cast_slice(indices)),
                 usage: wgpu::BufferUsages::INDEX,
             });
 
         Self {
             vertices,
             indices,
+            num_vertices,
             num_indices,
+            topology,
+        }
+    }
+}
+
+/// # The vertices and indices of a [`Geometry`], before upload to the GPU
+///
+/// Creating this doesn't require a GPU device, which makes it possible to
+/// inspect or test the geometry without one.
+pub struct GeometryData {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+}
+
+impl GeometryData {
+    /// # Create geometry data with flat shading
+    ///
+    /// This is the data that [`Geometry::new`] uploads.
+    pub fn from_object(operation: &dyn Object) -> Self {
+        Self::with_shading(operation, Shading::Flat, &white)
+    }
+
+    fn with_shading(
+        operation: &dyn Object,
+        shading: Shading,
+        color: &dyn Fn(Vec3) -> [f32; 3],
+    ) -> Self {
+        let tri_mesh = operation.tri_mesh();
+        let (vertices, indices) =
+            vertices_and_indices(&tri_mesh, shading, color);
+
+        Self { vertices, indices }
+    }
+}
+
+/// # How the normals of the geometry's vertices are computed
+#[derive(Clone, Copy)]
+enum Shading {
+    /// # Each vertex gets the normal of the triangle it belongs to
+    ///
+    /// Vertices are only shared between triangles that have the same normal.
+    /// This results in a faceted look.
+    Flat,
+
+    /// # Each vertex gets the average normal of all triangles it belongs to
+    ///
+    /// Vertices are shared between all triangles that have the same position.
+    /// The normals of those triangles are weighted by triangle area, then
+    /// averaged. This makes curved surfaces look smooth.
+    Smooth,
+}
+
+/// # Convert a triangle mesh into vertices and indices
+///
+/// Vertices that are shared between triangles are only created once, and
+/// referenced by multiple indices. Which vertices are considered shared depends
+/// on the [`Shading`].
+///
+/// Positions and normals are compared after quantizing them to a grid (see
+/// [`quantize`]), so vertices that only differ by floating-point noise are
+/// merged. With [`Shading::Flat`], the normals are normalized before that, so
+/// coplanar triangles share vertices, regardless of their area.
+///
+/// The color of each vertex is determined by calling `color` with its position.
+///
+/// Degenerate triangles (see [`Triangle::is_valid`]) are skipped. They don't
+/// have a meaningful normal, and wouldn't be visible anyway.
+fn vertices_and_indices(
+    tri_mesh: &TriMesh,
+    shading: Shading,
+    color: &dyn Fn(Vec3) -> [f32; 3],
+) -> (Vec<Vertex>, Vec<u32>) {
+    let valid = TriMesh {
+        triangles: tri_mesh
+            .triangles
+            .iter()
+            .filter(|triangle| triangle.inner.is_valid())
+            .map(|triangle| MeshTriangle {
+                inner: triangle.inner,
+                is_internal: triangle.is_internal,
+            })
+            .collect(),
+    };
+
+    let num_degenerate = tri_mesh.triangles.len() - valid.triangles.len();
+    if num_degenerate > 0 {
+        eprintln!("Skipping {num_degenerate} degenerate triangle(s).");
+    }
+
+    #[cfg(feature = "parallel")]
+    let triangles = triangles_and_normals_parallel(&valid);
+    #[cfg(not(feature = "parallel"))]
+    let triangles = triangles_and_normals(&valid);
+
+    deduplicate_vertices(triangles, shading, color)
+}
+
+/// # Convert the triangles of a mesh to `f32`, and compute their normals
+///
+/// The length of each normal is proportional to the triangle's area, which
+/// makes it suitable for computing area-weighted averages.
+///
+/// With the `parallel` feature, [`triangles_and_normals_parallel`] is used
+/// instead, and this only exists to check that both produce the same result.
+#[cfg(any(not(feature = "parallel"), test))]
+fn triangles_and_normals(tri_mesh: &TriMesh) -> Vec<([Vec3; 3], Vec3)> {
+    tri_mesh
+        .all_triangles()
+        .zip(tri_mesh.face_normals())
+        .map(|(triangle, normal)| {
+            (triangle.map(|point| to_vec3(point.coords)), to_vec3(normal))
+        })
+        .collect()
+}
+
+/// # Parallel version of [`triangles_and_normals`]
+///
+/// The triangles are converted on multiple threads, but the result is in the
+/// same order as that of the serial version. Both get their normals from
+/// [`TriMesh::face_normals`].
+#[cfg(feature = "parallel")]
+fn triangles_and_normals_parallel(
+    tri_mesh: &TriMesh,
+) -> Vec<([Vec3; 3], Vec3)> {
+    use rayon::prelude::*;
+
+    tri_mesh
+        .triangles
+        .par_iter()
+        .zip(tri_mesh.face_normals())
+        .map(|(triangle, normal)| {
+            (
+                triangle.inner.map(|point| to_vec3(point.coords)),
+                to_vec3(normal),
+            )
+        })
+        .collect()
+}
+
+/// # Create shared vertices and indices from triangles and their normals
+///
+/// This is the part of [`vertices_and_indices`] that needs to run in order,
+/// since the index of each vertex depends on the vertices that came before.
+fn deduplicate_vertices(
+    triangles: Vec<([Vec3; 3], Vec3)>,
+    shading: Shading,
+    color: &dyn Fn(Vec3) -> [f32; 3],
+) -> (Vec<Vertex>, Vec<u32>) {
+    let mut indices_by_vertex = BTreeMap::new();
+
+    let mut indices = Vec::new();
+    let mut vertices = Vec::new();
+
+    for (triangle, normal) in triangles {
+        let face_normal = normal.normalize_or_zero();
+
+        for point in triangle {
+            let position = quantize(point, POSITION_QUANTUM);
+            let key = match shading {
+                Shading::Flat => {
+                    (position, quantize(face_normal, NORMAL_QUANTUM))
+                }
+                Shading::Smooth => (position, [0; 3]),
+            };
+
+            let index = *indices_by_vertex.entry(key).or_insert_with(|| {
+                let index = vertices.len() as u32;
+                vertices.push(Vertex {
+                    position: point.into(),
+                    normal: match shading {
+                        Shading::Flat => face_normal.into(),
+                        Shading::Smooth => Vec3::ZERO.into(),
+                    },
+                    color: color(point),
+                });
+                index
+            });
+
+            indices.push(index);
+
+            if let Shading::Smooth = shading {
+                let vertex = &mut vertices[index as usize];
+                vertex.normal = (Vec3::from(vertex.normal) + normal).into();
+            }
+        }
+    }
+
+    if let Shading::Smooth = shading {
+        for vertex in &mut vertices {
+            vertex.normal =
+                Vec3::from(vertex.normal).normalize_or_zero().into();
+        }
+    }
+
+    (vertices, indices)
+}
+
+/// # The grid size that vertex positions are quantized to
+const POSITION_QUANTUM: f32 = 1e-5;
+
+/// # The grid size that normalized vertex normals are quantized to
+const NORMAL_QUANTUM: f32 = 1e-4;
+
+/// # Quantize a vector to a grid, to make it usable as a key
+///
+/// Vectors that are closer together than the size of the grid usually end up
+/// with the same result. Vectors that are close to a grid boundary can still
+/// end up on different sides of it.
+fn quantize(vector: Vec3, quantum: f32) -> [i64; 3] {
+    vector.to_array().map(|s| (s / quantum).round() as i64)
+}
+
+/// # Place 2D triangles into the xy-plane of a triangle mesh
+fn lift_to_3d(triangles: impl IntoIterator<Item = Triangle<2>>) -> TriMesh {
+    TriMesh {
+        triangles: triangles
+            .into_iter()
+            .map(|triangle| MeshTriangle {
+                inner: Triangle {
+                    points: triangle.map(|point| {
+                        let [x, y] = point.coords.components;
+                        Point::from([x, y, Scalar::zero()])
+                    }),
+                },
+                is_internal: false,
+            })
+            .collect(),
+    }
+}
+
+/// # Make sure buffer contents are not empty
+///
+/// An object might not have any triangles, but some backends reject
+/// zero-sized buffers. In that case, the buffer gets some padding instead. It
+/// is never read, because nothing is drawn if there are no indices.
+pub fn buffer_contents(contents: &[u8]) -> &[u8] {
+    const PADDING: [u8; wgpu::COPY_BUFFER_ALIGNMENT as usize] =
+        [0; wgpu::COPY_BUFFER_ALIGNMENT as usize];
+
+    if contents.is_empty() {
+        &PADDING
+    } else {
+        contents
+    }
+}
+
+fn to_vec3(vector: Vector<3>) -> Vec3 {
+    Vec3::from(vector.components.map(|coord| coord.value() as f32))
+}
+
+/// # The default vertex color
+fn white(_: Vec3) -> [f32; 3] {
+    [1.; 3]
+}
+
+/// # Convert the indices of a triangle list into the indices of a line list
+///
+/// The resulting list has two indices per edge. Edges that are shared between
+/// triangles are only included once.
+fn edge_indices(triangle_indices: &[u32]) -> Vec<u32> {
+    let mut visited_edges = BTreeSet::new();
+    let mut indices = Vec::new();
+
+    for triangle in triangle_indices.chunks_exact(3) {
+        let &[a, b, c] = triangle else {
+            unreachable!("Chunks have exactly 3 elements.");
+        };
+
+        for [start, end] in [[a, b], [b, c], [c, a]] {
+            let edge = [start.min(end), start.max(end)];
+
+            if visited_edges.insert(edge) {
+                indices.extend([start, end]);
+            }
+        }
+    }
+
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3;
+
+    use crate::geometry::{MeshTriangle, TriMesh, Triangle};
+
+    use super::{
+        GeometryData, Shading, buffer_contents, edge_indices, lift_to_3d,
+        vertices_and_indices, white,
+    };
+
+    #[test]
+    fn geometry_data() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [2., 0., 0.], [0., 1., 0.]]);
+
+        let GeometryData { vertices, indices } =
+            GeometryData::from_object(&triangle);
+
+        assert_eq!(indices, [0, 1, 2]);
+
+        let positions = vertices.iter().map(|vertex| vertex.position);
+        assert!(positions.eq([[0., 0., 0.], [2., 0., 0.], [0., 1., 0.]]));
+
+        // With flat shading, every vertex has the normal of the triangle.
+        for vertex in vertices {
+            assert_eq!(vertex.normal, [0., 0., 1.]);
+            assert_eq!(vertex.color, [1.; 3]);
+        }
+    }
+
+    #[test]
+    fn shared_vertices() {
+        let tri_mesh = tri_mesh([
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]],
+            [[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+        ]);
+
+        let (vertices, indices) =
+            vertices_and_indices(&tri_mesh, Shading::Flat, &white);
+
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+    }
+
+    #[test]
+    fn shared_vertices_of_different_triangles() {
+        // Two coplanar triangles of different areas, that share the vertex at
+        // the origin. Its position differs by floating-point noise.
+        let tri_mesh = tri_mesh([
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            [[1e-9, 0., 0.], [-2., 0., 0.], [0., -2., 0.]],
+        ]);
+
+        let (vertices, indices) =
+            vertices_and_indices(&tri_mesh, Shading::Flat, &white);
+
+        assert_eq!(vertices.len(), 5);
+        assert_eq!(indices, [0, 1, 2, 0, 3, 4]);
+
+        for vertex in vertices {
+            assert_eq!(vertex.normal, [0., 0., 1.]);
+        }
+    }
+
+    #[test]
+    fn degenerate_triangles() {
+        let tri_mesh = tri_mesh([
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]],
+            [[0., 0., 0.], [2., 2., 0.], [3., 3., 0.]],
+            [[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            [[1., 0., 0.], [4., 4., 0.], [7., 8. + 1e-12, 0.]],
+        ]);
+
+        let (vertices, indices) =
+            vertices_and_indices(&tri_mesh, Shading::Flat, &white);
+
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+
+        for vertex in vertices {
+            assert_ne!(vertex.position, [2., 2., 0.]);
+            assert_ne!(vertex.position, [3., 3., 0.]);
+            assert_ne!(vertex.position, [4., 4., 0.]);
+        }
+    }
+
+    #[test]
+    fn smooth_normals() {
+        // The top half of an octahedron, which is the first step in
+        // subdividing a sphere.
+        let tri_mesh = tri_mesh([
+            [[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]],
+            [[0., 0., 1.], [0., 1., 0.], [-1., 0., 0.]],
+            [[0., 0., 1.], [-1., 0., 0.], [0., -1., 0.]],
+            [[0., 0., 1.], [0., -1., 0.], [1., 0., 0.]],
+        ]);
+
+        let (flat, _) = vertices_and_indices(&tri_mesh, Shading::Flat, &white);
+        let (smooth, indices) =
+            vertices_and_indices(&tri_mesh, Shading::Smooth, &white);
+
+        assert_eq!(flat.len(), 12);
+        assert_eq!(smooth.len(), 5);
+
+        let top = smooth[indices[0] as usize];
+        assert_eq!(top.normal, [0., 0., 1.]);
+
+        for vertex in flat {
+            let face_normal = Vec3::from(vertex.normal).normalize();
+            assert_ne!(Vec3::from(top.normal), face_normal);
+        }
+    }
+
+    #[test]
+    fn wireframe() {
+        let single_triangle =
+            tri_mesh([[[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]]]);
+        let shared_edge = tri_mesh([
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]],
+            [[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+        ]);
+
+        let num_edges = |tri_mesh| {
+            let (_, indices) =
+                vertices_and_indices(tri_mesh, Shading::Smooth, &white);
+            edge_indices(&indices).len() / 2
+        };
+
+        assert_eq!(num_edges(&single_triangle), 3);
+        assert_eq!(num_edges(&shared_edge), 5);
+    }
+
+    #[test]
+    fn triangles_2d() {
+        let triangles = [
+            [[0., 0.], [1., 0.], [1., 1.]],
+            [[0., 0.], [1., 1.], [0., 1.]],
+        ]
+        .map(Triangle::<2>::from);
+
+        let tri_mesh = lift_to_3d(triangles);
+        assert!(tri_mesh.all_triangles_2d().eq(triangles));
+
+        let (vertices, indices) =
+            vertices_and_indices(&tri_mesh, Shading::Flat, &white);
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+
+        for vertex in vertices {
+            let [_, _, z] = vertex.position;
+            assert_eq!(z, 0.);
+            assert_eq!(Vec3::from(vertex.normal).normalize(), Vec3::Z);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel() {
+        use crate::geometry::test_helpers::many_triangles;
+
+        use super::{
+            Vertex, deduplicate_vertices, triangles_and_normals,
+            triangles_and_normals_parallel,
+        };
+
+        let tri_mesh = many_triangles(10_000);
+
+        for shading in [Shading::Flat, Shading::Smooth] {
+            let serial = deduplicate_vertices(
+                triangles_and_normals(&tri_mesh),
+                shading,
+                &white,
+            );
+            let parallel = deduplicate_vertices(
+                triangles_and_normals_parallel(&tri_mesh),
+                shading,
+                &white,
+            );
+
+            let bytes = |(vertices, indices): &(Vec<Vertex>, Vec<u32>)| {
+                let vertices: &[u8] = bytemuck::cast_slice(vertices);
+                let indices: &[u8] = bytemuck::cast_slice(indices);
+                [vertices.to_vec(), indices.to_vec()]
+            };
+            assert_eq!(bytes(&serial), bytes(&parallel));
+        }
+    }
+
+    #[test]
+    fn empty_mesh() {
+        let tri_mesh = tri_mesh([]);
+
+        for shading in [Shading::Flat, Shading::Smooth] {
+            let (vertices, indices) =
+                vertices_and_indices(&tri_mesh, shading, &white);
+
+            assert!(vertices.is_empty());
+            assert!(indices.is_empty());
+            assert!(edge_indices(&indices).is_empty());
+
+            let vertices = buffer_contents(bytemuck::cast_slice(&vertices));
+            let indices = buffer_contents(bytemuck::cast_slice(&indices));
+            assert!(!vertices.is_empty());
+            assert!(!indices.is_empty());
+        }
+    }
+
+    fn tri_mesh<const N: usize>(triangles: [[[f64; 3]; 3]; N]) -> TriMesh {
+        TriMesh {
+            triangles: triangles
+                .into_iter()
+                .map(|points| MeshTriangle {
+                    inner: Triangle::from(points),
+                    is_internal: false,
+                })
+                .collect(),
         }
     }
 }
@@ -0,0 +1,109 @@
+use glam::Mat4;
+use wgpu::util::DeviceExt;
+
+use super::geometry::buffer_contents;
+
+/// # A copy of a [`Geometry`](super::geometry::Geometry), placed in the scene
+///
+/// The transform is applied to the vertices of the geometry, before the
+/// camera transform. Normals are transformed by the same matrix, which is only
+/// correct for rotations, translations, and uniform scaling.
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct Instance {
+    /// # The transform, as columns of a 4x4 matrix
+    pub transform: [[f32; 4]; 4],
+}
+
+impl Instance {
+    /// # The attributes of an instance, one per column of the transform
+    ///
+    /// These come after those of [`Vertex`](super::vertex::Vertex).
+    pub const ATTRIBUTES: &[wgpu::VertexAttribute] = &wgpu::vertex_attr_array![
+        3 => Float32x4,
+        4 => Float32x4,
+        5 => Float32x4,
+        6 => Float32x4,
+    ];
+
+    pub fn from_transform(transform: Mat4) -> Self {
+        Self {
+            transform: transform.to_cols_array_2d(),
+        }
+    }
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: size_of::<Self>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// # A buffer of instances, uploaded to the GPU
+///
+/// See [`Pipeline::draw_instanced`](super::pipeline::Pipeline::draw_instanced).
+pub struct Instances {
+    pub buffer: wgpu::Buffer,
+    pub num_instances: u32,
+}
+
+impl Instances {
+    pub fn new(device: &wgpu::Device, instances: &[Instance]) -> Self {
+        let Ok(num_instances) = instances.len().try_into() else {
+            panic!("Unsupported number of instances: `{}`", instances.len());
+        };
+
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents: buffer_contents(bytemuck::cast_slice(instances)),
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+
+        Self {
+            buffer,
+            num_instances,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{Mat4, Vec3};
+
+    use super::Instance;
+
+    #[test]
+    fn layout() {
+        let layout = Instance::layout();
+        assert_eq!(layout.array_stride, 64);
+        assert_eq!(layout.step_mode, wgpu::VertexStepMode::Instance);
+
+        let locations_and_offsets = layout
+            .attributes
+            .iter()
+            .map(|attribute| (attribute.shader_location, attribute.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(locations_and_offsets, [(3, 0), (4, 16), (5, 32), (6, 48)]);
+    }
+
+    #[test]
+    fn buffer_contents() {
+        let instances = [
+            Mat4::IDENTITY,
+            Mat4::from_translation(Vec3::new(1., 2., 3.)),
+        ]
+        .map(Instance::from_transform);
+
+        let bytes: &[u8] = bytemuck::cast_slice(&instances);
+        assert_eq!(bytes.len(), 2 * size_of::<Instance>());
+
+        // The transforms are stored column by column, so the translation is
+        // in the last column of the second instance.
+        let floats: &[f32] = bytemuck::cast_slice(bytes);
+        assert_eq!(floats[..16], Mat4::IDENTITY.to_cols_array());
+        assert_eq!(floats[28..32], [1., 2., 3., 1.]);
+    }
+}
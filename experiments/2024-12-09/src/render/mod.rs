@@ -25,6 +25,7 @@
 //! want to use, except maybe to get started.
 
 mod geometry;
+mod instance;
 mod pipeline;
 mod renderer;
 mod text;
@@ -3,11 +3,20 @@ use std::f32::consts::PI;
 use glam::{Mat4, Vec3};
 use wgpu::util::DeviceExt;
 
-use super::{geometry::Geometry, uniforms::Uniforms, vertex::Vertex};
+use super::{
+    geometry::Geometry,
+    instance::{Instance, Instances},
+    uniforms::Uniforms,
+    vertex::Vertex,
+};
 
 pub struct Pipeline {
-    render_pipeline: wgpu::RenderPipeline,
+    triangles: wgpu::RenderPipeline,
+    lines: wgpu::RenderPipeline,
     bind_group: wgpu::BindGroup,
+
+    /// # The instance that is used for geometry that is not drawn instanced
+    identity: Instances,
 }
 
 impl Pipeline {
@@ -52,7 +61,7 @@ impl Pipeline {
             "shaders/triangles.wgsl"
         ));
 
-        let render_pipeline =
+        let render_pipeline = |topology| {
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 label: None,
                 layout: Some(&pipeline_layout),
@@ -61,12 +70,15 @@ impl Pipeline {
                     entry_point: Some("vertex"),
                     compilation_options:
                         wgpu::PipelineCompilationOptions::default(),
-                    buffers: &[wgpu::VertexBufferLayout {
-                        array_stride: size_of::<Vertex>()
-                            as wgpu::BufferAddress,
-                        step_mode: wgpu::VertexStepMode::Vertex,
-                        attributes: Vertex::ATTRIBUTES,
-                    }],
+                    buffers: &[
+                        wgpu::VertexBufferLayout {
+                            array_stride: size_of::<Vertex>()
+                                as wgpu::BufferAddress,
+                            step_mode: wgpu::VertexStepMode::Vertex,
+                            attributes: Vertex::ATTRIBUTES,
+                        },
+                        Instance::layout(),
+                    ],
                 },
                 fragment: Some(wgpu::FragmentState {
                     module: &shader_module,
@@ -80,7 +92,7 @@ impl Pipeline {
                     })],
                 }),
                 primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    topology,
                     strip_index_format: None,
                     front_face: wgpu::FrontFace::Ccw,
                     cull_mode: None,
@@ -98,7 +110,10 @@ impl Pipeline {
                 multisample: wgpu::MultisampleState::default(),
                 multiview: None,
                 cache: None,
-            });
+            })
+        };
+        let triangles = render_pipeline(wgpu::PrimitiveTopology::TriangleList);
+        let lines = render_pipeline(wgpu::PrimitiveTopology::LineList);
 
         let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: None,
@@ -109,9 +124,14 @@ impl Pipeline {
             }],
         });
 
+        let identity =
+            Instances::new(device, &[Instance::from_transform(Mat4::IDENTITY)]);
+
         Pipeline {
-            render_pipeline,
+            triangles,
+            lines,
             bind_group,
+            identity,
         }
     }
 
@@ -120,15 +140,40 @@ impl Pipeline {
         render_pass: &mut wgpu::RenderPass,
         geometry: &Geometry,
     ) {
-        if geometry.num_indices > 0 {
+        self.draw_instanced(render_pass, geometry, &self.identity);
+    }
+
+    /// # Draw one copy of the geometry for each of the instances
+    ///
+    /// All copies share the vertex and index buffers of the geometry.
+    pub fn draw_instanced(
+        &self,
+        render_pass: &mut wgpu::RenderPass,
+        geometry: &Geometry,
+        instances: &Instances,
+    ) {
+        let render_pipeline = match geometry.topology {
+            wgpu::PrimitiveTopology::LineList => &self.lines,
+            wgpu::PrimitiveTopology::TriangleList => &self.triangles,
+            topology => {
+                panic!("Unsupported primitive topology: `{topology:?}`")
+            }
+        };
+
+        if geometry.num_indices > 0 && instances.num_instances > 0 {
             render_pass.set_index_buffer(
                 geometry.indices.slice(..),
                 wgpu::IndexFormat::Uint32,
             );
             render_pass.set_vertex_buffer(0, geometry.vertices.slice(..));
-            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_vertex_buffer(1, instances.buffer.slice(..));
+            render_pass.set_pipeline(render_pipeline);
             render_pass.set_bind_group(0, &self.bind_group, &[]);
-            render_pass.draw_indexed(0..geometry.num_indices, 0, 0..1);
+            render_pass.draw_indexed(
+                0..geometry.num_indices,
+                0,
+                0..instances.num_instances,
+            );
         }
     }
 }
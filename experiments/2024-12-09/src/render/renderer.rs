@@ -15,6 +15,17 @@ pub struct Renderer {
     pub pipeline: Pipeline,
     pub depth_view: wgpu::TextureView,
     pub text_renderer: TextRenderer,
+
+    /// # Whether to render the geometry with smooth shading
+    ///
+    /// See [`Geometry::new_smooth`].
+    pub smooth_shading: bool,
+
+    /// # Whether to render only the edges of the triangles
+    ///
+    /// Takes precedence over [`Renderer::smooth_shading`]. See
+    /// [`Geometry::new_wireframe`].
+    pub wireframe: bool,
 }
 
 impl Renderer {
@@ -84,13 +95,21 @@ impl Renderer {
             pipeline,
             depth_view,
             text_renderer,
+            smooth_shading: false,
+            wireframe: false,
         })
     }
 
     pub fn render(&mut self, operations: &OperationView) -> anyhow::Result<()> {
         let selected_operation = operations.selected();
 
-        let geometry = Geometry::new(&self.device, selected_operation);
+        let geometry = if self.wireframe {
+            Geometry::new_wireframe(&self.device, selected_operation)
+        } else if self.smooth_shading {
+            Geometry::new_smooth(&self.device, selected_operation)
+        } else {
+            Geometry::new(&self.device, selected_operation)
+        };
 
         let mut encoder = self
             .device
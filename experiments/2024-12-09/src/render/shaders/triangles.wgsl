@@ -9,24 +9,41 @@ var<uniform> uniforms: Uniforms;
 struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) normal: vec3<f32>,
+    @location(2) color: vec3<f32>,
+}
+
+struct InstanceInput {
+    @location(3) transform_0: vec4<f32>,
+    @location(4) transform_1: vec4<f32>,
+    @location(5) transform_2: vec4<f32>,
+    @location(6) transform_3: vec4<f32>,
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) normal: vec3<f32>,
+    @location(1) color: vec3<f32>,
 }
 
 @vertex
-fn vertex(in: VertexInput) -> VertexOutput {
+fn vertex(in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let model = mat4x4(
+        instance.transform_0,
+        instance.transform_1,
+        instance.transform_2,
+        instance.transform_3,
+    );
+
     var out: VertexOutput;
-    out.position = uniforms.transform * vec4(in.position, 1.0);
-    out.normal = (uniforms.transform_for_normals * vec4(in.normal, 0.0)).xyz;
+    out.position = uniforms.transform * model * vec4(in.position, 1.0);
+    out.normal = (uniforms.transform_for_normals * model * vec4(in.normal, 0.0)).xyz;
+    out.color = in.color;
 
     return out;
 }
 
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-    var color = vec4(in.normal, 1.0);
+    var color = vec4(in.normal * in.color, 1.0);
     return color;
 }
@@ -3,11 +3,29 @@
 pub struct Vertex {
     pub position: [f32; 3],
     pub normal: [f32; 3],
+    pub color: [f32; 3],
 }
 
 impl Vertex {
     pub const ATTRIBUTES: &[wgpu::VertexAttribute] = &wgpu::vertex_attr_array![
         0 => Float32x3,
         1 => Float32x3,
+        2 => Float32x3,
     ];
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Vertex;
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<Vertex>(), 36);
+
+        let locations_and_offsets = Vertex::ATTRIBUTES
+            .iter()
+            .map(|attribute| (attribute.shader_location, attribute.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(locations_and_offsets, [(0, 0), (1, 12), (2, 24)]);
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::extra::triangulate::{TriangulationPoint, surface::SurfaceMesh};
+
+/// # Adjacency information for the triangles of a [`SurfaceMesh`]
+///
+/// Each triangle is split into three half-edges, which go from one of its
+/// points to the next. Two triangles are neighbors, if one of them has a
+/// half-edge that is the reverse of a half-edge of the other. Those two
+/// half-edges are twins.
+///
+/// This relies on the triangles of the mesh having a consistent winding, which
+/// is the case for meshes created by [`SurfaceMesh::new`].
+pub struct HalfEdgeMesh {
+    points: Vec<TriangulationPoint>,
+    indices_by_point: BTreeMap<TriangulationPoint, usize>,
+
+    /// # The half-edges, three per triangle
+    ///
+    /// The half-edges of the triangle at index `i` are at the indices `3 * i`,
+    /// `3 * i + 1`, and `3 * i + 2`. Each goes from the point of the triangle
+    /// with the same index to the next one.
+    half_edges: Vec<HalfEdge>,
+
+    half_edges_by_points: BTreeMap<[usize; 2], usize>,
+    outgoing_half_edges: Vec<Vec<usize>>,
+}
+
+impl HalfEdgeMesh {
+    pub fn new(surface_mesh: &SurfaceMesh) -> Self {
+        let mut points = Vec::new();
+        let mut indices_by_point = BTreeMap::new();
+        let mut outgoing_half_edges = Vec::new();
+
+        let mut half_edges = Vec::new();
+        let mut half_edges_by_points = BTreeMap::new();
+
+        for triangle in &surface_mesh.triangles {
+            let [a, b, c] = triangle.points.map(|point| {
+                *indices_by_point.entry(point).or_insert_with(|| {
+                    points.push(point);
+                    outgoing_half_edges.push(Vec::new());
+                    points.len() - 1
+                })
+            });
+
+            for [start, end] in [[a, b], [b, c], [c, a]] {
+                let index = half_edges.len();
+
+                half_edges.push(HalfEdge { start, end });
+                half_edges_by_points.insert([start, end], index);
+                outgoing_half_edges[start].push(index);
+            }
+        }
+
+        Self {
+            points,
+            indices_by_point,
+            half_edges,
+            half_edges_by_points,
+            outgoing_half_edges,
+        }
+    }
+
+    /// # Access the neighbors of a triangle
+    ///
+    /// Returns the index of the neighboring triangle across each edge of the
+    /// triangle, in the order of its half-edges. Edges that are on the
+    /// boundary of the mesh have no neighbor.
+    pub fn neighbors(&self, triangle: usize) -> [Option<usize>; 3] {
+        [0, 1, 2].map(|i| {
+            let twin = self.twin(triangle * 3 + i)?;
+            Some(twin / 3)
+        })
+    }
+
+    /// # Access the edges that connect a point to its neighbors
+    ///
+    /// The first point of each returned edge is the provided point. Returns an
+    /// empty list, if the point is not part of the mesh.
+    pub fn edges_around_vertex(
+        &self,
+        point: &TriangulationPoint,
+    ) -> Vec<[TriangulationPoint; 2]> {
+        let Some(&index) = self.indices_by_point.get(point) else {
+            return Vec::new();
+        };
+
+        let mut neighbors = BTreeSet::new();
+
+        for &outgoing in &self.outgoing_half_edges[index] {
+            neighbors.insert(self.half_edges[outgoing].end);
+
+            // If the point is on the boundary, one of its edges only exists
+            // as an incoming half-edge, without a twin.
+            let incoming = previous(outgoing);
+            if self.twin(incoming).is_none() {
+                neighbors.insert(self.half_edges[incoming].start);
+            }
+        }
+
+        neighbors
+            .into_iter()
+            .map(|neighbor| [*point, self.points[neighbor]])
+            .collect()
+    }
+
+    /// # Determine whether an edge is on the boundary of the mesh
+    ///
+    /// Returns `false`, if the edge is shared by two triangles, or if it isn't
+    /// part of the mesh at all.
+    #[allow(unused)] // useful for remeshing
+    pub fn is_boundary_edge(&self, [a, b]: [TriangulationPoint; 2]) -> bool {
+        let (Some(&a), Some(&b)) =
+            (self.indices_by_point.get(&a), self.indices_by_point.get(&b))
+        else {
+            return false;
+        };
+
+        let is_in_mesh = self.half_edges_by_points.contains_key(&[a, b])
+            || self.half_edges_by_points.contains_key(&[b, a]);
+        let is_shared = self.half_edges_by_points.contains_key(&[a, b])
+            && self.half_edges_by_points.contains_key(&[b, a]);
+
+        is_in_mesh && !is_shared
+    }
+
+    /// # Iterate over the edges on the boundary of the mesh
+    ///
+    /// Each edge is returned in the direction of the half-edge that it belongs
+    /// to.
+    pub fn boundary_edges(
+        &self,
+    ) -> impl Iterator<Item = [TriangulationPoint; 2]> + '_ {
+        self.half_edges
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| self.twin(index).is_none())
+            .map(|(_, half_edge)| {
+                [half_edge.start, half_edge.end].map(|i| self.points[i])
+            })
+    }
+
+    fn twin(&self, half_edge: usize) -> Option<usize> {
+        let HalfEdge { start, end } = self.half_edges[half_edge];
+        self.half_edges_by_points.get(&[end, start]).copied()
+    }
+}
+
+struct HalfEdge {
+    start: usize,
+    end: usize,
+}
+
+/// # Compute the index of the previous half-edge in the same triangle
+fn previous(half_edge: usize) -> usize {
+    let triangle = half_edge / 3;
+    triangle * 3 + (half_edge + 2) % 3
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        extra::triangulate::surface::SurfaceMesh, test_helpers::plane_surface,
+    };
+
+    use super::HalfEdgeMesh;
+
+    #[test]
+    fn quad() {
+        let surface = plane_surface(0.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        assert_eq!(surface_mesh.triangle_count(), 2);
+
+        let half_edge_mesh = HalfEdgeMesh::new(&surface_mesh);
+
+        // Each triangle has one neighbor, across the quad's diagonal.
+        for (triangle, other) in [(0, 1), (1, 0)] {
+            let neighbors = half_edge_mesh.neighbors(triangle);
+
+            assert_eq!(neighbors.iter().flatten().count(), 1);
+            assert!(neighbors.contains(&Some(other)));
+        }
+
+        let [a, b, c] = surface_mesh.triangles[0].points;
+        let edges = [[a, b], [b, c], [c, a]];
+        let diagonal = edges.into_iter().find(|&[a, b]| {
+            a.point_surface.u != b.point_surface.u
+                && a.point_surface.v != b.point_surface.v
+        });
+        let diagonal = diagonal.unwrap();
+
+        for edge in edges {
+            assert_eq!(half_edge_mesh.is_boundary_edge(edge), edge != diagonal);
+        }
+        assert_eq!(half_edge_mesh.boundary_edges().count(), 4);
+
+        // Both ends of the diagonal connect to all other points.
+        for point in diagonal {
+            assert_eq!(half_edge_mesh.edges_around_vertex(&point).len(), 3);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+/// # A bounding volume hierarchy
+///
+/// Organizes items, identified by their index, into a tree of nested bounding
+/// boxes. Queries can then skip whole subtrees whose bounding box shows that
+/// none of their items can be relevant.
+///
+/// The hierarchy only knows the bounding box of each item. Queries take a
+/// closure that does the precise check for a single item.
+#[derive(Debug)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+}
+
+impl Bvh {
+    /// # The maximum number of items in a leaf node
+    const MAX_ITEMS_PER_LEAF: usize = 4;
+
+    /// # Build a hierarchy from the bounding boxes of the items
+    pub fn new(aabbs: &[Aabb<3>]) -> Self {
+        let mut bvh = Self { nodes: Vec::new() };
+
+        let items = (0..aabbs.len()).collect();
+        if !aabbs.is_empty() {
+            bvh.build(aabbs, items);
+        }
+
+        bvh
+    }
+
+    /// # Find the item that a ray hits first
+    ///
+    /// `hit` is called for every item whose bounding box the ray might hit,
+    /// and returns the time of impact, if the ray hits the item. Returns the
+    /// index of the item with the lowest time of impact, together with that
+    /// time. If multiple items have the same time of impact, the one with the
+    /// lowest index is returned.
+    pub fn cast_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+        mut hit: impl FnMut(usize) -> Option<Scalar>,
+    ) -> Option<(usize, Scalar)> {
+        let mut nearest: Option<(usize, Scalar)> = None;
+        let mut stack = self.root();
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+
+            let Some(toi) = ray_hits_aabb(origin, dir, node.aabb()) else {
+                continue;
+            };
+            if let Some((_, min_toi)) = nearest {
+                if toi > min_toi {
+                    continue;
+                }
+            }
+
+            match node {
+                Node::Leaf { items, .. } => {
+                    for &item in items {
+                        let Some(toi) = hit(item) else {
+                            continue;
+                        };
+
+                        if is_better(nearest, (item, toi)) {
+                            nearest = Some((item, toi));
+                        }
+                    }
+                }
+                Node::Branch { children, .. } => {
+                    stack.extend(children);
+                }
+            }
+        }
+
+        nearest
+    }
+
+    /// # Find the item that is nearest to a point
+    ///
+    /// `distance_squared` is called for every item that might be nearest, and
+    /// returns the squared distance from the point to that item. If multiple
+    /// items are nearest, the one with the lowest index is returned.
+    pub fn nearest(
+        &self,
+        point: Point<3>,
+        mut distance_squared: impl FnMut(usize) -> Scalar,
+    ) -> Option<usize> {
+        let mut nearest: Option<(usize, Scalar)> = None;
+        let mut stack = self.root();
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+
+            if let Some((_, min_distance_squared)) = nearest {
+                let aabb_distance_squared =
+                    distance_squared_to_aabb(point, node.aabb());
+
+                if aabb_distance_squared > min_distance_squared {
+                    continue;
+                }
+            }
+
+            match node {
+                Node::Leaf { items, .. } => {
+                    for &item in items {
+                        let distance_squared = distance_squared(item);
+
+                        if is_better(nearest, (item, distance_squared)) {
+                            nearest = Some((item, distance_squared));
+                        }
+                    }
+                }
+                Node::Branch { children, .. } => {
+                    stack.extend(children);
+                }
+            }
+        }
+
+        nearest.map(|(item, _)| item)
+    }
+
+    fn root(&self) -> Vec<usize> {
+        if self.nodes.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        }
+    }
+
+    /// # Add a node for the provided items, and return its index
+    fn build(&mut self, aabbs: &[Aabb<3>], mut items: Vec<usize>) -> usize {
+        let aabb = items
+            .iter()
+            .map(|&item| aabbs[item])
+            .reduce(|a, b| a.union(&b))
+            .expect("Nodes are only built for non-empty sets of items");
+
+        let index = self.nodes.len();
+
+        if items.len() <= Self::MAX_ITEMS_PER_LEAF {
+            self.nodes.push(Node::Leaf { aabb, items });
+            return index;
+        }
+
+        // Split along the longest axis, at the median of the items' centers.
+        let size = aabb.size().components;
+        let axis = (0..3)
+            .max_by_key(|&axis| size[axis])
+            .expect("Range is not empty");
+        items.sort_by_key(|&item| aabbs[item].center().coords.components[axis]);
+        let right = items.split_off(items.len() / 2);
+        let left = items;
+
+        // Push a placeholder, so the index is taken before the children are
+        // built. It's replaced below.
+        self.nodes.push(Node::Leaf {
+            aabb,
+            items: Vec::new(),
+        });
+
+        let children = [self.build(aabbs, left), self.build(aabbs, right)];
+        self.nodes[index] = Node::Branch { aabb, children };
+
+        index
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Leaf { aabb: Aabb<3>, items: Vec<usize> },
+    Branch { aabb: Aabb<3>, children: [usize; 2] },
+}
+
+impl Node {
+    fn aabb(&self) -> &Aabb<3> {
+        match self {
+            Self::Leaf { aabb, .. } => aabb,
+            Self::Branch { aabb, .. } => aabb,
+        }
+    }
+}
+
+/// # Decide whether a candidate is nearer than the current nearest item
+///
+/// Ties are broken by index, so the result doesn't depend on the order in which
+/// the hierarchy is traversed.
+fn is_better(
+    nearest: Option<(usize, Scalar)>,
+    (item, distance): (usize, Scalar),
+) -> bool {
+    let Some((nearest_item, nearest_distance)) = nearest else {
+        return true;
+    };
+
+    (distance, item) < (nearest_distance, nearest_item)
+}
+
+/// # Compute the time of impact of a ray with an AABB
+///
+/// Returns `0`, if the ray starts inside of the AABB, and `None`, if it
+/// doesn't hit the AABB at all.
+fn ray_hits_aabb(
+    origin: Point<3>,
+    dir: Vector<3>,
+    aabb: &Aabb<3>,
+) -> Option<Scalar> {
+    let mut t_min = 0f64;
+    let mut t_max = f64::INFINITY;
+
+    for axis in 0..3 {
+        let origin = origin.coords.components[axis].into_f64();
+        let dir = dir.components[axis].into_f64();
+        let min = aabb.min.coords.components[axis].into_f64();
+        let max = aabb.max.coords.components[axis].into_f64();
+
+        if dir == 0. {
+            if origin < min || origin > max {
+                return None;
+            }
+            continue;
+        }
+
+        let [t1, t2] = [(min - origin) / dir, (max - origin) / dir];
+        t_min = t_min.max(t1.min(t2));
+        t_max = t_max.min(t1.max(t2));
+
+        if t_min > t_max {
+            return None;
+        }
+    }
+
+    Some(Scalar::from(t_min))
+}
+
+fn distance_squared_to_aabb(point: Point<3>, aabb: &Aabb<3>) -> Scalar {
+    let mut closest = point;
+    for axis in 0..3 {
+        let [min, max] =
+            [aabb.min, aabb.max].map(|corner| corner.coords.components[axis]);
+        closest.coords.components[axis] =
+            point.coords.components[axis].clamp(min, max);
+    }
+
+    point.distance_squared_to(&closest)
+}
@@ -1,25 +1,199 @@
-use spade::Triangulation;
+use spade::Triangulation as _;
 
 use crate::extra::triangulate::TriangulationPoint;
 
+/// # Compute the constrained Delaunay triangulation of a set of points
+///
+/// `constraint_loop` is a closed loop of points. Each point is connected to the
+/// next, and the last one to the first. The triangulation is guaranteed to
+/// contain those edges, which makes it possible to remove the triangles
+/// outside of the loop afterwards. Pass an empty loop, if there are no
+/// constraints.
+///
+/// `points` are inserted into the triangulation in addition to the points of
+/// the loop, without any constraints.
+///
+/// The triangles cover the convex hull of all points, including the parts that
+/// are outside of the loop, and are returned in no particular order.
+///
+/// ## Errors
+///
+/// Returns [`DegenerateInput`], if no triangles could be created. This is the
+/// case, if there are less than three distinct points, or if all points are
+/// collinear.
+///
+/// ## Example
+///
+/// This is a binary crate, so the example isn't run as a doctest. The `square`
+/// test below does the same thing.
+///
+/// ```ignore
+/// let surface = SweptCurve::plane_from_coord_system(
+///     [0., 0., 0.],
+///     [[1., 0., 0.], [0., 1., 0.]],
+/// );
+/// let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(|point| {
+///     TriangulationPoint::from_surface_point(Point::from(point), &surface)
+/// });
+///
+/// let triangles = triangles(square, [])?;
+/// assert_eq!(triangles.len(), 2);
+/// ```
 pub fn triangles(
-    points_from_half_edges: impl IntoIterator<Item = TriangulationPoint>,
-    points_from_surface: impl IntoIterator<Item = TriangulationPoint>,
-) -> Vec<[TriangulationPoint; 3]> {
-    let mut triangulation = spade::ConstrainedDelaunayTriangulation::<_>::new();
+    constraint_loop: impl IntoIterator<Item = TriangulationPoint>,
+    points: impl IntoIterator<Item = TriangulationPoint>,
+) -> Result<Vec<[TriangulationPoint; 3]>, DegenerateInput> {
+    let mut triangulation = Triangulation::new();
 
-    // We're passing duplicate points to the triangulation here. It doesn't seem
-    // to mind though.
-    triangulation
-        .add_constraint_edges(points_from_half_edges, true)
-        .unwrap();
+    triangulation.add_constraint_loop(constraint_loop);
 
-    for point in points_from_surface {
-        triangulation.insert(point).unwrap();
+    for point in points {
+        triangulation.insert(point);
     }
 
-    triangulation
-        .inner_faces()
-        .map(|triangle| triangle.vertices().map(|vertex| *vertex.data()))
-        .collect()
+    let triangles = triangulation.triangles();
+
+    if triangles.is_empty() {
+        return Err(DegenerateInput {
+            num_points: triangulation.inner.num_vertices(),
+        });
+    }
+
+    Ok(triangles)
+}
+
+/// # The input of [`triangles`] didn't result in any triangles
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Triangulating {num_points} points created no triangles. There need to \
+    be at least three points that are not collinear."
+)]
+pub struct DegenerateInput {
+    /// # The number of distinct points that were triangulated
+    pub num_points: usize,
+}
+
+/// # A Delaunay triangulation that points can be added to incrementally
+///
+/// Inserting a point only re-triangulates the area around that point, instead
+/// of rebuilding the whole triangulation from scratch.
+pub struct Triangulation {
+    inner: spade::ConstrainedDelaunayTriangulation<TriangulationPoint>,
+}
+
+impl Triangulation {
+    pub fn new() -> Self {
+        Self {
+            inner: spade::ConstrainedDelaunayTriangulation::new(),
+        }
+    }
+
+    /// # Add a closed loop of edges that the triangulation must preserve
+    pub fn add_constraint_loop(
+        &mut self,
+        points: impl IntoIterator<Item = TriangulationPoint>,
+    ) {
+        // We're passing duplicate points to the triangulation here. It doesn't
+        // seem to mind though.
+        self.inner
+            .add_constraint_edges(points, true)
+            .expect("Failed to add constraint edges to triangulation");
+    }
+
+    /// # Insert a point into the triangulation
+    pub fn insert(&mut self, point: TriangulationPoint) {
+        self.inner
+            .insert(point)
+            .expect("Failed to insert point into triangulation");
+    }
+
+    /// # Access the triangles of the triangulation
+    pub fn triangles(&self) -> Vec<[TriangulationPoint; 3]> {
+        self.inner
+            .inner_faces()
+            .map(|triangle| triangle.vertices().map(|vertex| *vertex.data()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Point;
+
+    use crate::{extra::triangulate::TriangulationPoint, test_helpers::plane};
+
+    use super::{Triangulation, triangles};
+
+    #[test]
+    fn incremental_insertion_matches_batch_triangulation() {
+        let surface = plane(0.);
+        let points = [
+            [0., 0.],
+            [2., 0.],
+            [2., 2.],
+            [0., 2.],
+            [1., 1.],
+            [0.5, 1.5],
+            [1.5, 0.25],
+        ]
+        .map(|point| {
+            TriangulationPoint::from_surface_point(Point::from(point), &surface)
+        });
+
+        let mut triangulation = Triangulation::new();
+        let mut num_triangles = Vec::new();
+        for point in points {
+            triangulation.insert(point);
+            num_triangles.push(triangulation.triangles().len());
+        }
+
+        assert_eq!(num_triangles, [0, 0, 1, 2, 4, 6, 8]);
+        assert_eq!(
+            normalize(triangulation.triangles()),
+            normalize(triangles([], points).unwrap()),
+        );
+
+        fn normalize(
+            triangles: Vec<[TriangulationPoint; 3]>,
+        ) -> BTreeSet<[TriangulationPoint; 3]> {
+            triangles
+                .into_iter()
+                .map(|mut triangle| {
+                    triangle.sort();
+                    triangle
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn collinear_points_yield_no_triangles() {
+        let surface = plane(0.);
+        let points = [[0., 0.], [1., 1.], [2., 2.]].map(|point| {
+            TriangulationPoint::from_surface_point(Point::from(point), &surface)
+        });
+
+        assert!(triangles([], points).is_err());
+        assert!(triangles(points, []).is_err());
+    }
+
+    #[test]
+    fn square() {
+        let surface = plane(0.);
+        let [a, b, c, d] =
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(|point| {
+                TriangulationPoint::from_surface_point(
+                    Point::from(point),
+                    &surface,
+                )
+            });
+
+        let triangles = triangles([a, b, c, d], []).unwrap();
+        assert_eq!(triangles.len(), 2);
+
+        let points = triangles.into_iter().flatten().collect::<BTreeSet<_>>();
+        assert_eq!(points, BTreeSet::from([a, b, c, d]));
+    }
 }
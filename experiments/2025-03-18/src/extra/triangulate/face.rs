@@ -7,6 +7,7 @@ use std::{
 use fj_interop::{Color, MeshTriangle, Tolerance, TriMesh};
 use fj_math::{Aabb, Point, Triangle};
 use geo::{Contains, Coord, LineString, Polygon};
+use tracing::warn;
 
 use crate::{
     extra::triangulate::{delaunay::triangles, surface::SurfaceMesh},
@@ -31,7 +32,15 @@ pub fn triangulate_face(
             max: Point::from([size, size]),
         };
 
-        SurfaceMesh::new(&face.surface, &boundary, tolerance)
+        let mut surface_mesh =
+            SurfaceMesh::new(&face.surface, &boundary, tolerance);
+        surface_mesh.build_bvh();
+
+        if let Err(err) = surface_mesh.validate() {
+            warn!("{err}");
+        }
+
+        surface_mesh
     };
 
     let points_from_half_edges =
@@ -48,6 +57,10 @@ pub fn triangulate_face(
     });
 
     let triangles = triangles(points_from_half_edges, surface_points)
+        .unwrap_or_else(|err| {
+            warn!("{err}");
+            Vec::new()
+        })
         .into_iter()
         .filter(|triangle| {
             let points = triangle.map(|point| point.point_surface);
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::extra::triangulate::TriangulationPoint;
+
+/// # A grid of cells in surface coordinates, for looking up nearby points
+///
+/// Each point is stored in the cell that contains its surface position. A
+/// query then only needs to look at the points in the cells that overlap its
+/// search radius, instead of at all points.
+///
+/// This works best, if the cell size is about the same as the radius of the
+/// typical query.
+pub struct SpatialHashGrid {
+    cell_size: Scalar,
+    cells: BTreeMap<[i64; 2], Vec<TriangulationPoint>>,
+}
+
+impl SpatialHashGrid {
+    /// # Create an empty grid
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if `cell_size` is not positive.
+    pub fn new(cell_size: impl Into<Scalar>) -> Self {
+        let cell_size = cell_size.into();
+        assert!(
+            cell_size > Scalar::ZERO,
+            "Cell size must be positive: {cell_size:?}",
+        );
+
+        Self {
+            cell_size,
+            cells: BTreeMap::new(),
+        }
+    }
+
+    /// # Insert a point into the grid
+    pub fn insert(&mut self, point: TriangulationPoint) {
+        let cell = self.cell(point.point_surface);
+        self.cells.entry(cell).or_default().push(point);
+    }
+
+    /// # Find the point that is nearest to the given one, within a radius
+    ///
+    /// Only considers points that are closer than `radius`. Returns `None`, if
+    /// there are no such points.
+    pub fn nearest_within(
+        &self,
+        point: Point<2>,
+        radius: impl Into<Scalar>,
+    ) -> Option<TriangulationPoint> {
+        let radius = radius.into();
+
+        let [min_u, min_v] = self.cell(point - [radius, radius]);
+        let [max_u, max_v] = self.cell(point + [radius, radius]);
+
+        let mut nearest = None;
+
+        for u in min_u..=max_u {
+            for v in min_v..=max_v {
+                let Some(points) = self.cells.get(&[u, v]) else {
+                    continue;
+                };
+
+                for &candidate in points {
+                    let distance_squared =
+                        candidate.point_surface.distance_squared_to(&point);
+
+                    if distance_squared >= radius * radius {
+                        continue;
+                    }
+
+                    if let Some((_, min_distance_squared)) = nearest {
+                        if distance_squared >= min_distance_squared {
+                            continue;
+                        }
+                    }
+
+                    nearest = Some((candidate, distance_squared));
+                }
+            }
+        }
+
+        nearest.map(|(point, _)| point)
+    }
+
+    fn cell(&self, point: Point<2>) -> [i64; 2] {
+        point
+            .coords
+            .components
+            .map(|s| (s / self.cell_size).floor().into_f64() as i64)
+    }
+}
+
+/// # Create a regular grid of points that spans the provided boundary
+///
+/// There are `nu` points along the u-axis and `nv` points along the v-axis.
+/// The outermost points are on the edges of the boundary. If there is only one
+/// point along an axis, it is placed at the boundary's minimum on that axis.
+///
+/// The points are ordered by u first, then by v.
+#[allow(unused)] // useful for sampling surfaces independently of their approx
+pub fn grid_points(boundary: &Aabb<2>, nu: usize, nv: usize) -> Vec<Point<2>> {
+    let steps = |n: usize, min: Scalar, max: Scalar| {
+        (0..n).map(move |i| {
+            let t = if n > 1 { i as f64 / (n - 1) as f64 } else { 0. };
+
+            min.lerp(max, t)
+        })
+    };
+
+    let [min, max] = [boundary.min, boundary.max];
+
+    steps(nu, min.u, max.u)
+        .flat_map(|u| steps(nv, min.v, max.v).map(move |v| Point::from([u, v])))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Vector};
+
+    use crate::{extra::triangulate::TriangulationPoint, test_helpers::plane};
+
+    use super::{SpatialHashGrid, grid_points};
+
+    #[test]
+    fn nearest_within() {
+        let surface = plane(0.);
+        let triangulation_point = |point: Point<2>| {
+            TriangulationPoint::from_surface_point(point, &surface)
+        };
+
+        // A simple xorshift generator, so the test is reproducible without
+        // pulling in a dependency.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        let mut grid = SpatialHashGrid::new(0.01);
+        let mut points = Vec::new();
+        for _ in 0..10_000 {
+            let point = triangulation_point(Point::from([random(), random()]));
+
+            grid.insert(point);
+            points.push(point);
+        }
+
+        let expected = points[1234];
+        let query = expected.point_surface + Vector::from([1e-4, -1e-4]);
+
+        let nearest_by_brute_force = points
+            .iter()
+            .copied()
+            .min_by_key(|point| point.point_surface.distance_squared_to(&query))
+            .unwrap();
+        assert_eq!(nearest_by_brute_force, expected);
+
+        assert_eq!(grid.nearest_within(query, 0.01), Some(expected));
+        assert_eq!(grid.nearest_within(Point::from([2., 2.]), 0.01), None);
+    }
+
+    #[test]
+    fn grid_points_over_unit_square() {
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let points = grid_points(&boundary, 3, 3);
+
+        assert_eq!(
+            points,
+            [
+                [0., 0.],
+                [0., 0.5],
+                [0., 1.],
+                [0.5, 0.],
+                [0.5, 0.5],
+                [0.5, 1.],
+                [1., 0.],
+                [1., 0.5],
+                [1., 1.],
+            ]
+            .map(Point::from)
+        );
+    }
+}
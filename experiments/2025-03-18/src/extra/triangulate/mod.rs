@@ -1,5 +1,8 @@
-mod delaunay;
+mod adjacency;
+mod bvh;
+pub mod delaunay;
 mod face;
+mod grid;
 mod point;
 mod surface;
 
@@ -1,8 +1,19 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+};
+
 use fj_interop::{Tolerance, TriMesh};
-use fj_math::{Aabb, Point, Scalar, Triangle};
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+use geo::{Contains, Coord, LineString, Polygon};
+use tracing::warn;
 
 use crate::{
-    extra::triangulate::{TriangulationPoint, delaunay::triangles},
+    extra::triangulate::{
+        TriangulationPoint, adjacency::HalfEdgeMesh, bvh::Bvh,
+        delaunay::triangles, grid::SpatialHashGrid,
+    },
+    geometry::SurfaceGeometry,
     topology::surface::Surface,
 };
 
@@ -10,6 +21,11 @@ use crate::{
 pub struct SurfaceMesh {
     pub points: Vec<TriangulationPoint>,
     pub triangles: Vec<MeshTriangle>,
+
+    /// # Accelerates queries, if present
+    ///
+    /// See [`SurfaceMesh::build_bvh`].
+    bvh: Option<Bvh>,
 }
 
 impl SurfaceMesh {
@@ -18,12 +34,734 @@ impl SurfaceMesh {
         boundary: &Aabb<2>,
         tolerance: impl Into<Tolerance>,
     ) -> Self {
-        let surface_mesh = surface_to_mesh(surface, boundary, tolerance);
+        Self::from_polygon(surface, &boundary.corners_2d(), tolerance)
+    }
+
+    /// # Create a mesh of the surface within the boundary of a polygon
+    ///
+    /// The polygon can be concave. Its edges are passed to the triangulation
+    /// as constraints, and triangles that end up outside of the polygon are
+    /// removed.
+    pub fn from_polygon(
+        surface: &Surface,
+        polygon: &[Point<2>],
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        let surface_mesh = surface_to_mesh(surface, polygon, tolerance);
         check_that_triangles_are_valid(&surface_mesh);
 
         surface_mesh
     }
 
+    #[allow(unused)] // useful for regression-testing meshing changes
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// # Compute the total area of the mesh in 3D space
+    #[allow(unused)] // useful for regression-testing meshing changes
+    pub fn total_area(&self) -> Scalar {
+        self.triangles
+            .iter()
+            .map(|triangle| triangle.to_global_triangle().area())
+            .fold(Scalar::ZERO, |total, area| total + area)
+    }
+
+    /// # Compute the centroid of the mesh in 3D space
+    ///
+    /// This is the average of the triangles' centers, weighted by their area.
+    /// Unlike the average of the mesh's points, it doesn't depend on how
+    /// densely different regions of the surface are meshed.
+    ///
+    /// Returns `None`, if the total area of the mesh is zero, which is the
+    /// case for an empty mesh, for example.
+    #[allow(unused)] // useful for placing labels
+    pub fn centroid(&self) -> Option<Point<3>> {
+        let total_area = self.total_area();
+        if total_area.is_zero() {
+            return None;
+        }
+
+        let weighted_sum = self
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let triangle = triangle.to_global_triangle();
+                triangle.center().coords * triangle.area()
+            })
+            .fold(Vector::from([0., 0., 0.]), |sum, weighted| sum + weighted);
+
+        Some(Point {
+            coords: weighted_sum / total_area,
+        })
+    }
+
+    /// # Compute how much each triangle is distorted by the surface
+    ///
+    /// Returns, for each triangle, the ratio of its area in 3D space to its
+    /// area in surface coordinates. A ratio of `1` means the area is preserved.
+    /// Ratios far from `1` indicate where the parameterization of the surface
+    /// stretches or compresses the mesh.
+    #[allow(unused)] // useful for judging texture-mapping quality
+    pub fn distortion(&self) -> Vec<Scalar> {
+        self.triangles
+            .iter()
+            .map(|triangle| {
+                triangle.to_global_triangle().area()
+                    / triangle.to_surface_triangle().area()
+            })
+            .collect()
+    }
+
+    /// # Check that the mesh is a valid surface patch
+    ///
+    /// Triangles store their points by value, so there are no point indices
+    /// that could be out of bounds. That leaves two things to check for: None
+    /// of the triangles must be degenerate in 3D space, and the mesh must be a
+    /// topological disk (see [`SurfaceMesh::euler_characteristic`]).
+    pub fn validate(&self) -> Result<(), MeshValidationError> {
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            let triangle = triangle.to_global_triangle();
+
+            if !triangle.is_valid() {
+                return Err(MeshValidationError::DegenerateTriangle {
+                    index,
+                    triangle,
+                });
+            }
+        }
+
+        let euler_characteristic = self.euler_characteristic();
+        if euler_characteristic != 1 {
+            return Err(MeshValidationError::NotADisk {
+                euler_characteristic,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// # Find the edges that belong to exactly one triangle
+    ///
+    /// Those are the edges on the boundary of the mesh. Edges that are shared
+    /// between two triangles are interior edges, and are not returned.
+    #[allow(unused)] // useful for stitching adjacent surface meshes
+    pub fn boundary_edges(&self) -> Vec<[TriangulationPoint; 2]> {
+        let mut boundary_edges = HalfEdgeMesh::new(self)
+            .boundary_edges()
+            .map(|mut edge| {
+                edge.sort();
+                edge
+            })
+            .collect::<Vec<_>>();
+        boundary_edges.sort();
+
+        boundary_edges
+    }
+
+    /// # Compute the Euler characteristic of the mesh
+    ///
+    /// This is `V - E + F`, where `V` is the number of distinct points of all
+    /// triangles, `E` the number of distinct edges, and `F` the number of
+    /// triangles. A mesh that is a topological disk, as is expected of a
+    /// surface patch, has an Euler characteristic of 1. Every hole in the mesh
+    /// reduces it by one, as does every additional disconnected patch.
+    pub fn euler_characteristic(&self) -> i64 {
+        let edges = self
+            .triangles
+            .iter()
+            .flat_map(|triangle| {
+                let [a, b, c] = triangle.points;
+                [[a, b], [b, c], [c, a]]
+            })
+            .map(|mut edge| {
+                edge.sort();
+                edge
+            })
+            .collect::<BTreeSet<_>>();
+
+        let [v, e, f] =
+            [self.vertices().len(), edges.len(), self.triangles.len()]
+                .map(|n| n as i64);
+
+        v - e + f
+    }
+
+    /// # Smooth the mesh by moving its interior points
+    ///
+    /// This is Laplacian smoothing: In each iteration, every point that is not
+    /// on one of the [boundary edges](Self::boundary_edges) is moved to the
+    /// average position of its neighbors, in surface coordinates. Boundary
+    /// points stay where they are.
+    ///
+    /// The global form of the moved points is recomputed from the surface,
+    /// which must be the one that the mesh was created from.
+    #[allow(unused)] // useful for improving triangle quality
+    pub fn smooth(&mut self, surface: &Surface, iterations: usize) {
+        let boundary_points = self
+            .boundary_edges()
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        for _ in 0..iterations {
+            let half_edge_mesh = HalfEdgeMesh::new(self);
+            let neighbors_by_point = self
+                .triangles
+                .iter()
+                .flat_map(|triangle| triangle.points)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|point| {
+                    let neighbors = half_edge_mesh
+                        .edges_around_vertex(&point)
+                        .into_iter()
+                        .map(|[_, neighbor]| neighbor)
+                        .collect::<Vec<_>>();
+
+                    (point, neighbors)
+                });
+
+            let moved_points = neighbors_by_point
+                .into_iter()
+                .filter(|(point, _)| !boundary_points.contains(point))
+                .map(|(point, neighbors)| {
+                    let sum = neighbors
+                        .iter()
+                        .map(|neighbor| neighbor.point_surface.coords)
+                        .fold(Vector::from([0., 0.]), |sum, coords| {
+                            sum + coords
+                        });
+                    let average = Point {
+                        coords: sum / neighbors.len() as f64,
+                    };
+
+                    let moved = TriangulationPoint::from_surface_point(
+                        average,
+                        surface.geometry.as_ref(),
+                    );
+
+                    (point, moved)
+                })
+                .collect::<BTreeMap<_, _>>();
+
+            let points = self
+                .points
+                .iter_mut()
+                .chain(self.triangles.iter_mut().flat_map(|t| &mut t.points));
+            for point in points {
+                if let Some(moved) = moved_points.get(point) {
+                    *point = *moved;
+                }
+            }
+        }
+
+        if self.bvh.is_some() {
+            self.build_bvh();
+        }
+    }
+
+    /// # Reduce the number of triangles by collapsing edges
+    ///
+    /// Repeatedly collapses the shortest interior edge, until the mesh has no
+    /// more than `target_triangles` triangles left. Interior edges are those
+    /// that are shared between two triangles, and collapsing one of them
+    /// removes both of those triangles.
+    ///
+    /// The two points of a collapsed edge are merged into their midpoint. If
+    /// one of them is on the boundary of the mesh, that point is kept instead,
+    /// so the boundary doesn't change. Edges that connect two boundary points
+    /// are never collapsed, and neither are edges whose collapse would flip the
+    /// winding of any remaining triangle.
+    ///
+    /// If no more edges can be collapsed, this stops before reaching the target.
+    ///
+    /// The global form of merged points is recomputed from the surface, which
+    /// must be the one that the mesh was created from.
+    #[allow(unused)] // useful for generating lower levels of detail
+    pub fn simplify(&mut self, surface: &Surface, target_triangles: usize) {
+        let boundary_points = self
+            .boundary_edges()
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        while self.triangles.len() > target_triangles {
+            let mut num_triangles_by_edge = BTreeMap::new();
+            for triangle in &self.triangles {
+                let [a, b, c] = triangle.points;
+
+                for mut edge in [[a, b], [b, c], [c, a]] {
+                    edge.sort();
+                    *num_triangles_by_edge.entry(edge).or_insert(0) += 1;
+                }
+            }
+
+            let mut interior_edges = num_triangles_by_edge
+                .into_iter()
+                .filter_map(|(edge, num_triangles)| {
+                    (num_triangles == 2).then_some(edge)
+                })
+                .collect::<Vec<_>>();
+            interior_edges.sort_by_key(|[a, b]| {
+                a.point_surface.distance_squared_to(&b.point_surface)
+            });
+
+            let collapse = interior_edges.into_iter().find_map(|[a, b]| {
+                let merged = match [a, b].map(|p| boundary_points.contains(&p))
+                {
+                    [false, false] => TriangulationPoint::from_surface_point(
+                        a.point_surface.lerp(&b.point_surface, 0.5),
+                        surface.geometry.as_ref(),
+                    ),
+                    [true, false] => a,
+                    [false, true] => b,
+                    [true, true] => return None,
+                };
+
+                let triangles = self.collapsed_triangles([a, b], merged)?;
+
+                Some(([a, b], merged, triangles))
+            });
+
+            let Some(([a, b], merged, triangles)) = collapse else {
+                break;
+            };
+
+            self.triangles = triangles;
+            self.points.retain(|&point| point != a && point != b);
+            if !boundary_points.contains(&merged) {
+                self.points.push(merged);
+            }
+        }
+
+        if self.bvh.is_some() {
+            self.build_bvh();
+        }
+    }
+
+    /// # Compute the triangles that result from collapsing an edge
+    ///
+    /// Returns `None`, if the collapse would flip the winding of a triangle.
+    fn collapsed_triangles(
+        &self,
+        edge: [TriangulationPoint; 2],
+        merged: TriangulationPoint,
+    ) -> Option<Vec<MeshTriangle>> {
+        let mut triangles = Vec::new();
+
+        for triangle in &self.triangles {
+            let num_points_on_edge = triangle
+                .points
+                .iter()
+                .filter(|point| edge.contains(point))
+                .count();
+
+            if num_points_on_edge == 2 {
+                // This triangle contains the whole edge. It collapses into a
+                // line, and is removed.
+                continue;
+            }
+
+            let collapsed = MeshTriangle {
+                points: triangle.points.map(|point| {
+                    if edge.contains(&point) { merged } else { point }
+                }),
+            };
+
+            let winding = triangle.to_surface_triangle().winding();
+            let winding_after = collapsed.to_surface_triangle().winding();
+
+            if winding_after.is_none() || winding_after != winding {
+                return None;
+            }
+
+            triangles.push(collapsed);
+        }
+
+        Some(triangles)
+    }
+
+    /// # Refine the mesh where it deviates too much from the surface
+    ///
+    /// A triangle is refined, if the surface point at its center deviates from
+    /// the triangle's center by more than `max_error`. All edges of a refined
+    /// triangle are split at their midpoint, in surface coordinates, which
+    /// splits the triangle into four.
+    ///
+    /// Adjacent triangles share the split edges, so they are split as well, to
+    /// keep the mesh free of T-junctions. Depending on how many of their edges
+    /// are split, they are split into two, three, or four triangles.
+    ///
+    /// This is repeated, until all triangles are within `max_error`, or the
+    /// maximum number of iterations has been reached. Flat regions of the
+    /// surface don't cause any splits themselves, so most triangles end up
+    /// where the surface is curved most strongly.
+    ///
+    /// The global form of new points is computed from the surface, which must
+    /// be the one that the mesh was created from.
+    #[allow(unused)] // useful for meshing curved surfaces economically
+    pub fn refine_by_curvature(
+        &mut self,
+        surface: &Surface,
+        max_error: impl Into<Scalar>,
+    ) {
+        // Each iteration halves the length of the split edges. If that's not
+        // enough after this many iterations, more likely won't help either.
+        const MAX_ITERATIONS: usize = 8;
+
+        let max_error = max_error.into();
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut num_triangles_by_edge = BTreeMap::new();
+            for triangle in &self.triangles {
+                for edge in edges(triangle) {
+                    *num_triangles_by_edge.entry(edge).or_insert(0) += 1;
+                }
+            }
+
+            let mut midpoints = BTreeMap::new();
+            for triangle in &self.triangles {
+                let center = triangle.to_surface_triangle().center();
+                let deviation = surface
+                    .geometry
+                    .point_from_local(center)
+                    .distance_to(&triangle.to_global_triangle().center());
+
+                if deviation <= max_error {
+                    continue;
+                }
+
+                for edge @ [a, b] in edges(triangle) {
+                    midpoints.entry(edge).or_insert_with(|| {
+                        TriangulationPoint::from_surface_point(
+                            a.point_surface.lerp(&b.point_surface, 0.5),
+                            surface.geometry.as_ref(),
+                        )
+                    });
+                }
+            }
+
+            if midpoints.is_empty() {
+                break;
+            }
+
+            self.triangles = self
+                .triangles
+                .iter()
+                .flat_map(|triangle| split_triangle(triangle, &midpoints))
+                .collect();
+            self.points.extend(midpoints.into_iter().filter_map(
+                |(edge, midpoint)| {
+                    let is_interior = num_triangles_by_edge[&edge] > 1;
+                    is_interior.then_some(midpoint)
+                },
+            ));
+        }
+
+        if self.bvh.is_some() {
+            self.build_bvh();
+        }
+    }
+
+    /// # Create a copy of the mesh that is offset along its normals
+    ///
+    /// Each point is moved by `distance` along its vertex normal, which is the
+    /// average of the normals of the triangles it belongs to, weighted by
+    /// their area. Negative distances move the points to the other side. The
+    /// triangles connect the same points as before, and the surface
+    /// coordinates of the points are not changed.
+    ///
+    /// Points whose vertex normal is zero (or too short to be normalized) stay
+    /// where they are. This happens, if a point only belongs to degenerate
+    /// triangles, or if the normals of its triangles cancel each other out, as
+    /// they do where a mesh is folded back onto itself.
+    ///
+    /// Nothing prevents the offset mesh from intersecting itself. This happens
+    /// where the mesh curves towards the offset direction, with a radius that
+    /// is smaller than the distance.
+    ///
+    /// The points of the offset mesh are no longer on the surface that this
+    /// mesh was created from, so the methods that take a surface don't work on
+    /// it.
+    #[allow(unused)] // useful for shell and thickness operations
+    pub fn offset(&self, distance: impl Into<Scalar>) -> SurfaceMesh {
+        let distance = distance.into();
+
+        let mut normals = BTreeMap::new();
+        for triangle in &self.triangles {
+            let [a, b, c] = triangle.points.map(|point| point.point_global);
+            let normal = (b - a).cross(&(c - a));
+
+            for point in triangle.points {
+                let sum =
+                    normals.entry(point).or_insert(Vector::from([0., 0., 0.]));
+                *sum = *sum + normal;
+            }
+        }
+
+        let offset_points = normals
+            .into_iter()
+            .filter_map(|(point, normal)| {
+                let offset = TriangulationPoint {
+                    point_surface: point.point_surface,
+                    point_global: point.point_global
+                        + normal.normalize_checked()? * distance,
+                };
+
+                Some((point, offset))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let offset = |point: &TriangulationPoint| {
+            offset_points.get(point).copied().unwrap_or(*point)
+        };
+
+        let mut surface_mesh = SurfaceMesh {
+            points: self.points.iter().map(offset).collect(),
+            triangles: self
+                .triangles
+                .iter()
+                .map(|triangle| MeshTriangle {
+                    points: triangle.points.map(|point| offset(&point)),
+                })
+                .collect(),
+            bvh: None,
+        };
+
+        if self.bvh.is_some() {
+            surface_mesh.build_bvh();
+        }
+
+        surface_mesh
+    }
+
+    /// # Weld another mesh to this one, along their shared boundary
+    ///
+    /// Each boundary point of `other` that is within `tolerance` of a boundary
+    /// point of this mesh (in 3D space) is replaced by that point. Then the
+    /// triangles and points of `other` are appended to this mesh. Where the
+    /// boundaries coincide, the triangles of both meshes now share their
+    /// edges, so those edges are no longer [boundary
+    /// edges](Self::boundary_edges).
+    ///
+    /// The meshes need to have a consistent winding, as is the case for
+    /// adjacent meshes created from surfaces whose normals point to the same
+    /// side. The surface coordinates of `other`'s points are kept as they are,
+    /// so if it was created from a different surface, the methods that take a
+    /// surface no longer work on the welded mesh.
+    #[allow(unused)] // useful for joining adjacent surface patches
+    pub fn weld(
+        &mut self,
+        other: &SurfaceMesh,
+        tolerance: impl Into<Tolerance>,
+    ) {
+        let tolerance = tolerance.into();
+
+        let boundary_points = self
+            .boundary_edges()
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+        let other_boundary_points = other
+            .boundary_edges()
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        let welded = other_boundary_points
+            .into_iter()
+            .filter_map(|other_point| {
+                let point =
+                    boundary_points.iter().copied().min_by_key(|point| {
+                        point
+                            .point_global
+                            .distance_to(&other_point.point_global)
+                    })?;
+
+                let distance =
+                    point.point_global.distance_to(&other_point.point_global);
+                (distance < tolerance.inner()).then_some((other_point, point))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        self.points.extend(other.points.iter().copied());
+        self.triangles
+            .extend(other.triangles.iter().map(|triangle| MeshTriangle {
+                points:
+                    triangle.points.map(|point| {
+                        welded.get(&point).copied().unwrap_or(point)
+                    }),
+            }));
+
+        if self.bvh.is_some() {
+            self.build_bvh();
+        }
+    }
+
+    /// # Write the mesh to the OBJ format
+    ///
+    /// Writes the global form of each point, so the mesh can be inspected in
+    /// external tools. Points that are shared between triangles are only
+    /// written once.
+    #[allow(unused)] // useful for occasional debugging
+    pub fn write_obj(&self, mut writer: impl io::Write) -> io::Result<()> {
+        let mut indices_by_point = BTreeMap::new();
+
+        for triangle in &self.triangles {
+            for point in triangle.points {
+                if indices_by_point.contains_key(&point) {
+                    continue;
+                }
+
+                // Indices in OBJ files are 1-based.
+                let index = indices_by_point.len() + 1;
+                indices_by_point.insert(point, index);
+
+                let [x, y, z] = point.point_global.coords.components;
+                writeln!(writer, "v {x} {y} {z}")?;
+            }
+        }
+
+        for triangle in &self.triangles {
+            let [a, b, c] =
+                triangle.points.map(|point| indices_by_point[&point]);
+            writeln!(writer, "f {a} {b} {c}")?;
+        }
+
+        Ok(())
+    }
+
+    /// # Access the distinct points of all triangles
+    ///
+    /// Unlike [`SurfaceMesh::points`], this includes the points on the
+    /// boundary. Points are returned in the order in which they first appear
+    /// in the triangles. This is the order that the indices returned by
+    /// [`SurfaceMesh::to_triangle_strips`] refer to.
+    #[allow(unused)] // useful for building render buffers
+    pub fn vertices(&self) -> Vec<TriangulationPoint> {
+        let mut seen = BTreeSet::new();
+
+        self.triangles
+            .iter()
+            .flat_map(|triangle| triangle.points)
+            .filter(|&point| seen.insert(point))
+            .collect()
+    }
+
+    /// # Convert the triangles of the mesh into triangle strips
+    ///
+    /// Each strip is a list of indices into [`SurfaceMesh::vertices`]. Every
+    /// three consecutive indices form a triangle, so each index after the
+    /// first two adds another triangle to the strip. Every other triangle has
+    /// its first two indices swapped, to maintain the winding, as is the
+    /// convention for triangle strips.
+    ///
+    /// Strips are built greedily, by starting at the first triangle that's not
+    /// yet part of a strip, and walking across its edges to adjacent
+    /// triangles for as long as possible. Of the three ways to start the
+    /// strip, the one resulting in the longest strip is chosen.
+    #[allow(unused)] // useful for bandwidth-efficient rendering
+    pub fn to_triangle_strips(&self) -> Vec<Vec<u32>> {
+        let indices_by_point = self
+            .vertices()
+            .into_iter()
+            .enumerate()
+            .map(|(index, point)| (point, index as u32))
+            .collect::<BTreeMap<_, _>>();
+
+        let half_edge_mesh = HalfEdgeMesh::new(self);
+        let mut is_in_strip = vec![false; self.triangles.len()];
+
+        let mut strips = Vec::new();
+
+        for start in 0..self.triangles.len() {
+            if is_in_strip[start] {
+                continue;
+            }
+
+            let (points, triangles) = [0, 1, 2]
+                .map(|rotation| {
+                    self.strip_from(
+                        start,
+                        rotation,
+                        &half_edge_mesh,
+                        &is_in_strip,
+                    )
+                })
+                .into_iter()
+                .max_by_key(|(_, triangles)| triangles.len())
+                .expect("Array is not empty");
+
+            for triangle in triangles {
+                is_in_strip[triangle] = true;
+            }
+            strips.push(
+                points
+                    .into_iter()
+                    .map(|point| indices_by_point[&point])
+                    .collect(),
+            );
+        }
+
+        strips
+    }
+
+    /// # Walk from a triangle to its neighbors, to build a triangle strip
+    ///
+    /// Returns the points of the strip, followed by the indices of the
+    /// triangles that it covers.
+    fn strip_from(
+        &self,
+        start: usize,
+        rotation: usize,
+        half_edge_mesh: &HalfEdgeMesh,
+        is_in_strip: &[bool],
+    ) -> (Vec<TriangulationPoint>, Vec<usize>) {
+        let mut points = self.triangles[start].points.to_vec();
+        points.rotate_left(rotation);
+
+        let mut triangles = vec![start];
+
+        loop {
+            let current = *triangles.last().expect("Started with a triangle");
+            let [p, q] = [points[points.len() - 2], points[points.len() - 1]];
+
+            // The neighbor across the edge between the last two points. Every
+            // edge has a triangle on each side at most, so it doesn't matter
+            // in which direction we look for that edge.
+            let current_points = self.triangles[current].points;
+            let neighbor = (0..3)
+                .find(|&i| {
+                    let edge = [current_points[i], current_points[(i + 1) % 3]];
+                    edge == [p, q] || edge == [q, p]
+                })
+                .and_then(|i| half_edge_mesh.neighbors(current)[i]);
+
+            let Some(neighbor) = neighbor else {
+                break;
+            };
+            if is_in_strip[neighbor] || triangles.contains(&neighbor) {
+                break;
+            }
+
+            let next = self.triangles[neighbor]
+                .points
+                .into_iter()
+                .find(|&point| point != p && point != q)
+                .expect("Triangle has a third point");
+
+            points.push(next);
+            triangles.push(neighbor);
+        }
+
+        (points, triangles)
+    }
+
+    /// # Convert the mesh into a triangle mesh in 3D space
+    ///
+    /// Uses the global form of each triangulation point, which is the surface
+    /// point mapped through the surface geometry.
     #[allow(unused)] // useful for occasional debugging
     pub fn to_tri_mesh(&self) -> TriMesh {
         let triangles = self
@@ -45,33 +783,233 @@ impl SurfaceMesh {
         TriMesh { triangles }
     }
 
+    /// # Build a bounding volume hierarchy over the triangles of the mesh
+    ///
+    /// Once built, [`SurfaceMesh::project_point`],
+    /// [`SurfaceMesh::nearest_point`], and [`SurfaceMesh::cast_ray`] use it to
+    /// skip most triangles, instead of checking all of them.
+    ///
+    /// [`SurfaceMesh::smooth`], [`SurfaceMesh::simplify`],
+    /// [`SurfaceMesh::refine_by_curvature`], and [`SurfaceMesh::weld`] rebuild
+    /// the hierarchy, if it is present, and [`SurfaceMesh::offset`] builds one
+    /// for the offset mesh. Changes that are made to `triangles` directly are
+    /// not tracked. Call this method again after making any.
+    pub fn build_bvh(&mut self) {
+        let aabbs = self
+            .triangles
+            .iter()
+            .map(|triangle| triangle.to_global_triangle().bounding_box())
+            .collect::<Vec<_>>();
+
+        self.bvh = Some(Bvh::new(&aabbs));
+    }
+
+    /// # Find the point of the mesh that is nearest to the provided one
+    ///
+    /// Points are compared by their global form, so this can be used to pick
+    /// a point of the mesh in 3D space.
+    #[allow(unused)] // useful for picking points in the viewer
+    pub fn nearest_point(&self, target: Point<3>) -> &TriangulationPoint {
+        let points = match &self.bvh {
+            Some(bvh) => {
+                let index = bvh.nearest(target, |index| {
+                    self.triangles[index]
+                        .points
+                        .iter()
+                        .map(|point| {
+                            point.point_global.distance_squared_to(&target)
+                        })
+                        .fold(Scalar::MAX, Scalar::min)
+                });
+
+                index
+                    .map(|index| &self.triangles[index].points)
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+            }
+            None => self
+                .triangles
+                .iter()
+                .flat_map(|triangle| &triangle.points)
+                .collect(),
+        };
+
+        points
+            .into_iter()
+            .min_by_key(|point| point.point_global.distance_squared_to(&target))
+            .expect(
+                "Surface mesh can't be empty. At the very least, there must be \
+                two triangles from the AABB.",
+            )
+    }
+
+    /// # Cast a ray against the mesh, in 3D space
+    ///
+    /// Returns the index of the triangle that the ray hits first, the time of
+    /// impact, and the barycentric coordinates of the hit on that triangle.
+    /// Returns `None`, if the ray doesn't hit any triangle.
+    ///
+    /// Unless [`SurfaceMesh::build_bvh`] has been called, this checks every
+    /// triangle of the mesh.
+    #[allow(unused)] // useful for picking in the viewer
+    pub fn cast_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+    ) -> Option<(usize, Scalar, [Scalar; 3])> {
+        let cast_ray_against = |index: usize| {
+            self.triangles[index].to_global_triangle().cast_local_ray(
+                origin,
+                dir,
+                f64::INFINITY,
+                true,
+            )
+        };
+
+        let nearest_hit = match &self.bvh {
+            Some(bvh) => bvh.cast_ray(origin, dir, cast_ray_against),
+            None => {
+                let mut nearest_hit = None;
+
+                for index in 0..self.triangles.len() {
+                    let Some(toi) = cast_ray_against(index) else {
+                        continue;
+                    };
+
+                    if let Some((_, min_toi)) = nearest_hit {
+                        if toi >= min_toi {
+                            continue;
+                        }
+                    }
+
+                    nearest_hit = Some((index, toi));
+                }
+
+                nearest_hit
+            }
+        };
+
+        nearest_hit.map(|(index, toi)| {
+            let barycentric_coords = self.triangles[index]
+                .to_global_triangle()
+                .point_to_barycentric_coords(origin + dir * toi);
+
+            (index, toi, barycentric_coords)
+        })
+    }
+
+    /// # Determine whether a point is inside of the mesh, in 3D space
+    ///
+    /// This only makes sense for closed meshes. The result for a mesh with a
+    /// boundary is not defined, and neither is the result for points that are
+    /// on the mesh.
+    ///
+    /// Casts a ray from the point and counts how many triangles it hits. If
+    /// that number is odd, the point is inside. If the ray passes through a
+    /// point or an edge of the mesh, or runs parallel to a triangle that it
+    /// hits, it could hit the same spot twice or miss it completely. Then the
+    /// direction of the ray is changed, and the test is repeated. Returns
+    /// `None`, if this happens for all directions that are tried.
+    ///
+    /// Degenerate triangles (see [`Triangle::is_valid`]) are ignored. They
+    /// have no area, so rays can't pass through them.
+    ///
+    /// This checks every triangle of the mesh, regardless of whether
+    /// [`SurfaceMesh::build_bvh`] has been called.
+    #[allow(unused)] // useful for boolean operations
+    pub fn contains_point(&self, point: Point<3>) -> Option<bool> {
+        // Arbitrary directions that are not aligned with any axis, to make it
+        // unlikely that they happen to align with a mesh that is.
+        let directions = [
+            [1., 0.3, 0.7],
+            [-0.4, 1., 0.2],
+            [0.1, -0.6, 1.],
+            [0.8, 0.9, -0.35],
+        ]
+        .map(Vector::from);
+
+        let epsilon = Scalar::from(1e-9);
+
+        // Returns `None`, if the number of hits is ambiguous.
+        let count_hits = |dir: Vector<3>| {
+            let mut num_hits = 0;
+
+            for triangle in &self.triangles {
+                let triangle = triangle.to_global_triangle();
+                if !triangle.is_valid() {
+                    continue;
+                }
+
+                let Some(toi) =
+                    triangle.cast_local_ray(point, dir, f64::INFINITY, false)
+                else {
+                    continue;
+                };
+
+                let is_near_edge = triangle
+                    .point_to_barycentric_coords(point + dir * toi)
+                    .into_iter()
+                    .any(|coord| coord < epsilon);
+                let is_parallel =
+                    triangle.normal().normalize().dot(&dir.normalize()).abs()
+                        < epsilon;
+
+                if is_near_edge || is_parallel {
+                    return None;
+                }
+
+                num_hits += 1;
+            }
+
+            Some(num_hits)
+        };
+
+        directions
+            .into_iter()
+            .find_map(count_hits)
+            .map(|num_hits| num_hits % 2 == 1)
+    }
+
     pub fn project_point(
         &self,
         point_global: Point<3>,
         tolerance: Tolerance,
     ) -> Point<2> {
-        let mut projection = None;
+        let projection = match &self.bvh {
+            Some(bvh) => bvh
+                .nearest(point_global, |index| {
+                    let (_, distance) =
+                        self.triangles[index].project_point(point_global);
+                    distance * distance
+                })
+                .map(|index| self.triangles[index].project_point(point_global)),
+            None => {
+                let mut projection = None;
 
-        for triangle in &self.triangles {
-            let (point_surface, distance) =
-                triangle.project_point(point_global);
+                for triangle in &self.triangles {
+                    let (point_surface, distance) =
+                        triangle.project_point(point_global);
 
-            let Some((_, min_distance)) = projection else {
-                projection = Some((point_surface, distance));
-                continue;
-            };
+                    let Some((_, min_distance)) = projection else {
+                        projection = Some((point_surface, distance));
+                        continue;
+                    };
+
+                    if distance < min_distance {
+                        projection = Some((point_surface, distance));
+                    }
+                }
 
-            if distance < min_distance {
-                projection = Some((point_surface, distance));
+                projection
             }
-        }
+        };
 
         let Some((point_surface, distance)) = projection else {
             unreachable!(
                 "Surface mesh can't be empty. At the very least, there must be \
-                two triangles from the AABB. This means that the loop above \
-                ran at least twice, and the first time it ran it definitely \
-                initialized `projection`."
+                two triangles from the AABB, and the nearest of those always \
+                initializes `projection`."
             );
         };
 
@@ -92,7 +1030,7 @@ impl SurfaceMesh {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq)]
 pub struct MeshTriangle {
     pub points: [TriangulationPoint; 3],
 }
@@ -128,46 +1066,316 @@ impl MeshTriangle {
     }
 }
 
+/// # Error validating a [`SurfaceMesh`]
+#[derive(Debug, thiserror::Error)]
+pub enum MeshValidationError {
+    /// # A triangle is degenerate in 3D space
+    #[error("Triangle at index {index} is degenerate: {triangle:?}")]
+    DegenerateTriangle { index: usize, triangle: Triangle<3> },
+
+    /// # The mesh is not a topological disk
+    #[error(
+        "Mesh is not a topological disk (Euler characteristic \
+        {euler_characteristic})"
+    )]
+    NotADisk { euler_characteristic: i64 },
+}
+
 fn surface_to_mesh(
     surface: &Surface,
-    boundary: &Aabb<2>,
+    polygon: &[Point<2>],
     tolerance: impl Into<Tolerance>,
 ) -> SurfaceMesh {
-    let approx = surface.geometry.approximate(boundary, tolerance.into());
+    let tolerance = tolerance.into();
 
-    let curvature_points = approx
-        .curvature
-        .into_iter()
-        .map(|point_surface| {
-            TriangulationPoint::from_surface_point(
-                point_surface,
-                surface.geometry.as_ref(),
-            )
-        })
-        .collect::<Vec<_>>();
+    let aabb = Aabb::<2>::from_points(polygon.iter().copied())
+        .expect("Boundary polygon must not be empty");
+    let approx = surface.geometry.approximate(&aabb, tolerance);
 
-    let boundary_points = approx.boundary.into_iter().map(|point_surface| {
+    let boundary_loop = boundary_loop(polygon, approx.boundary, tolerance);
+    let boundary_points = subdivide_boundary(
+        &boundary_loop,
+        surface.geometry.as_ref(),
+        tolerance,
+    )
+    .into_iter()
+    .map(|point_surface| {
         TriangulationPoint::from_surface_point(
             point_surface,
             surface.geometry.as_ref(),
         )
     });
 
-    let mut all_points = curvature_points.clone();
-    all_points.extend(boundary_points);
-
-    let triangles = triangles([], all_points)
-        .into_iter()
-        .map(|triangle| MeshTriangle { points: triangle })
-        .collect();
+    let mut grid = SpatialHashGrid::new(tolerance.inner());
 
-    SurfaceMesh {
-        points: curvature_points,
-        triangles,
+    let mut boundary_loop = Vec::new();
+    for point in boundary_points {
+        push_unless_coincident(&mut boundary_loop, &mut grid, point, tolerance);
     }
-}
 
-fn check_that_triangles_are_valid(surface_mesh: &SurfaceMesh) {
+    let polygon = Polygon::new(
+        LineString::new(polygon.iter().copied().map(coord).collect()),
+        Vec::new(),
+    );
+
+    let mut curvature_points = Vec::new();
+    for point in approx.curvature {
+        // Checking against the polygon's bounding box is much cheaper, and
+        // already rules out most points that are outside of it.
+        if !aabb.contains_point(point) || !polygon.contains(&coord(point)) {
+            continue;
+        }
+
+        let point = TriangulationPoint::from_surface_point(
+            point,
+            surface.geometry.as_ref(),
+        );
+        push_unless_coincident(
+            &mut curvature_points,
+            &mut grid,
+            point,
+            tolerance,
+        );
+    }
+
+    let mut triangles: Vec<_> =
+        triangles(boundary_loop, curvature_points.clone())
+            .unwrap_or_else(|err| {
+                warn!("{err}");
+                Vec::new()
+            })
+            .into_iter()
+            .filter(|triangle| {
+                let points = triangle.map(|point| point.point_surface);
+                polygon.contains(&coord(Triangle { points }.center()))
+            })
+            .map(|triangle| MeshTriangle { points: triangle })
+            .map(|triangle| {
+                align_with_surface_normal(triangle, surface.geometry.as_ref())
+            })
+            .collect();
+
+    // Don't depend on the order in which the triangulation happens to return
+    // its triangles. This makes the output reproducible, even if that order
+    // changes.
+    triangles.sort_by_key(|triangle| {
+        let mut points = triangle.points;
+        points.sort();
+        points
+    });
+
+    SurfaceMesh {
+        points: curvature_points,
+        triangles,
+        bvh: None,
+    }
+}
+
+/// # Order the boundary of a polygon into a loop
+///
+/// Returns the vertices of the polygon, in order. The points that approximate
+/// the boundary of the surface are inserted between them, if they are located
+/// on one of the polygon's edges. All other boundary points are ignored.
+fn boundary_loop(
+    polygon: &[Point<2>],
+    boundary: Vec<Point<2>>,
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    let mut points_on_edges = polygon
+        .iter()
+        .enumerate()
+        .map(|(i, &point)| ((i, Scalar::ZERO), point))
+        .collect::<Vec<_>>();
+
+    for point in boundary {
+        let edges = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .enumerate();
+
+        for (i, (&a, &b)) in edges {
+            let ab = b - a;
+            let t = (point - a).dot(&ab) / ab.dot(&ab);
+
+            let is_on_edge = t > Scalar::ZERO
+                && t < Scalar::ONE
+                && point.distance_to(&(a + ab * t)) < tolerance.inner();
+
+            if is_on_edge {
+                points_on_edges.push(((i, t), point));
+                break;
+            }
+        }
+    }
+
+    points_on_edges.sort_by_key(|&(position, _)| position);
+    points_on_edges
+        .into_iter()
+        .map(|(_, point)| point)
+        .collect()
+}
+
+/// # Make sure a triangle faces the same way as the surface
+///
+/// The winding of the triangles that come out of the triangulation depends on
+/// its implementation. This flips any triangle whose normal disagrees with the
+/// normal of the surface at the triangle's center.
+fn align_with_surface_normal(
+    mut triangle: MeshTriangle,
+    surface: &dyn SurfaceGeometry,
+) -> MeshTriangle {
+    let center = triangle.to_surface_triangle().center();
+    let surface_normal = surface.normal_at(center);
+
+    let normal = triangle.to_global_triangle().normal();
+    if normal.dot(&surface_normal) < Scalar::ZERO {
+        triangle.points.swap(1, 2);
+    }
+
+    triangle
+}
+
+/// # Subdivide the edges of a boundary loop, until they are within tolerance
+///
+/// The surface approximation only covers the boundary of the area that it
+/// approximates. Polygon edges that run through the interior of that area, or
+/// parts of the boundary that the approximation doesn't sample densely enough,
+/// would be represented by a single straight chord otherwise.
+///
+/// Each edge is split at its midpoint in surface coordinates, for as long as
+/// the surface deviates from the chord there by more than the tolerance. This
+/// adds points according to the curvature and length of each edge.
+fn subdivide_boundary(
+    boundary_loop: &[Point<2>],
+    surface: &dyn SurfaceGeometry,
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    // Protects against surfaces that can't be approximated by subdividing, for
+    // whatever reason. Splitting each edge into more than 2^16 parts should
+    // never be necessary.
+    const MAX_DEPTH: usize = 16;
+
+    fn subdivide(
+        [a, b]: [Point<2>; 2],
+        surface: &dyn SurfaceGeometry,
+        tolerance: Tolerance,
+        depth: usize,
+        points: &mut Vec<Point<2>>,
+    ) {
+        let midpoint = a.lerp(&b, 0.5);
+
+        let chord_midpoint = surface
+            .point_from_local(a)
+            .lerp(&surface.point_from_local(b), 0.5);
+        let deviation = surface
+            .point_from_local(midpoint)
+            .distance_to(&chord_midpoint);
+
+        if deviation <= tolerance.inner() || depth >= MAX_DEPTH {
+            return;
+        }
+
+        subdivide([a, midpoint], surface, tolerance, depth + 1, points);
+        points.push(midpoint);
+        subdivide([midpoint, b], surface, tolerance, depth + 1, points);
+    }
+
+    let mut points = Vec::new();
+
+    let edges = boundary_loop
+        .iter()
+        .copied()
+        .zip(boundary_loop.iter().copied().cycle().skip(1));
+    for (a, b) in edges {
+        points.push(a);
+        subdivide([a, b], surface, tolerance, 0, &mut points);
+    }
+
+    points
+}
+
+/// # Access the edges of a triangle, with their points sorted
+///
+/// This makes edges comparable, regardless of which of the triangles that
+/// share them they came from.
+fn edges(triangle: &MeshTriangle) -> [[TriangulationPoint; 2]; 3] {
+    let [a, b, c] = triangle.points;
+
+    [[a, b], [b, c], [c, a]].map(|mut edge| {
+        edge.sort();
+        edge
+    })
+}
+
+/// # Split a triangle at the midpoints of some of its edges
+///
+/// The resulting triangles have the same winding as the original one.
+fn split_triangle(
+    triangle: &MeshTriangle,
+    midpoints: &BTreeMap<[TriangulationPoint; 2], TriangulationPoint>,
+) -> Vec<MeshTriangle> {
+    let midpoints = edges(triangle).map(|edge| midpoints.get(&edge).copied());
+
+    // Rotate the triangle, so the split edges come first. Then the cases below
+    // only need to consider one arrangement each.
+    let rotation = (0..3)
+        .find(|&i| {
+            let [m0, m1, m2] = [0, 1, 2].map(|j| midpoints[(i + j) % 3]);
+            match [m0, m1, m2].iter().flatten().count() {
+                1 => m0.is_some(),
+                2 => m2.is_none(),
+                _ => true,
+            }
+        })
+        .unwrap_or(0);
+    let [p0, p1, p2] = [0, 1, 2].map(|j| triangle.points[(rotation + j) % 3]);
+    let [m0, m1, m2] = [0, 1, 2].map(|j| midpoints[(rotation + j) % 3]);
+
+    let triangles = match [m0, m1, m2] {
+        [None, None, None] => vec![[p0, p1, p2]],
+        [Some(m0), None, None] => vec![[p0, m0, p2], [m0, p1, p2]],
+        [Some(m0), Some(m1), None] => {
+            vec![[p0, m0, m1], [m0, p1, m1], [p0, m1, p2]]
+        }
+        [Some(m0), Some(m1), Some(m2)] => {
+            vec![[p0, m0, m2], [m0, p1, m1], [m2, m1, p2], [m0, m1, m2]]
+        }
+        _ => unreachable!("Triangle has been rotated to avoid this case"),
+    };
+
+    triangles
+        .into_iter()
+        .map(|points| MeshTriangle { points })
+        .collect()
+}
+
+fn coord(point: Point<2>) -> Coord {
+    let [x, y] = point.coords.components.map(|s| s.into_f64());
+    Coord { x, y }
+}
+
+/// # Add a point, unless it coincides with one that is already there
+///
+/// Points are compared in surface coordinates, against all points that have
+/// been added to the grid so far. Coincident points would result in degenerate
+/// triangles, if they were passed to the triangulation.
+fn push_unless_coincident(
+    points: &mut Vec<TriangulationPoint>,
+    grid: &mut SpatialHashGrid,
+    point: TriangulationPoint,
+    tolerance: Tolerance,
+) {
+    let is_coincident = grid
+        .nearest_within(point.point_surface, tolerance.inner())
+        .is_some();
+
+    if !is_coincident {
+        grid.insert(point);
+        points.push(point);
+    }
+}
+
+fn check_that_triangles_are_valid(surface_mesh: &SurfaceMesh) {
     for triangle in &surface_mesh.triangles {
         assert!(
             triangle.to_surface_triangle().is_valid(),
@@ -179,3 +1387,901 @@ fn check_that_triangles_are_valid(surface_mesh: &SurfaceMesh) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, f64::consts::PI};
+
+    use approx::assert_abs_diff_eq;
+    use fj_interop::Tolerance;
+    use fj_math::{Aabb, Point, Scalar, Vector};
+
+    use crate::{
+        extra::triangulate::{TriangulationPoint, grid::grid_points},
+        geometry::SurfaceApprox,
+        test_helpers::{TestSurface, cylinder_surface, plane_surface},
+        topology::surface::Surface,
+    };
+
+    use super::{MeshTriangle, MeshValidationError, SurfaceMesh};
+
+    #[test]
+    fn finer_tolerance_yields_more_points() {
+        let surface = cylinder_surface();
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([PI, 1.]),
+        };
+
+        let coarse = SurfaceMesh::new(&surface, &boundary, 0.1);
+        let fine = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        // The cylinder is only curved along its u-axis, so all of its points
+        // are on the boundary, and `points` is empty. We need to look at the
+        // triangles instead.
+        let num_points = |surface_mesh: &SurfaceMesh| {
+            surface_mesh
+                .triangles
+                .iter()
+                .flat_map(|triangle| triangle.points)
+                .collect::<BTreeSet<_>>()
+                .len()
+        };
+
+        assert!(num_points(&fine) > num_points(&coarse));
+    }
+
+    #[test]
+    fn coincident_points_are_merged() {
+        let surface = Surface {
+            geometry: Box::new(TestSurface::xy_plane().with_approximation(
+                |boundary| {
+                    let [min, max] = [boundary.min, boundary.max];
+
+                    SurfaceApprox {
+                        curvature: vec![
+                            min + Vector::from([1e-12, 1e-12]),
+                            Point::from([0.5, 0.25]),
+                        ],
+                        boundary: vec![
+                            min,
+                            Point::from([min.u, max.v]),
+                            Point::from([max.u, min.v]),
+                            max,
+                        ],
+                    }
+                },
+            )),
+        };
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        assert_eq!(surface_mesh.points.len(), 1);
+        for triangle in &surface_mesh.triangles {
+            assert_ne!(triangle.to_surface_triangle().area(), Scalar::ZERO);
+        }
+    }
+
+    #[test]
+    fn concave_polygon() {
+        let surface = plane_surface(0.);
+
+        // An L-shape, with its reentrant corner at `[1., 1.]`.
+        let polygon =
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]]
+                .map(Point::from);
+
+        let surface_mesh = SurfaceMesh::from_polygon(&surface, &polygon, 0.001);
+
+        let mut area = Scalar::ZERO;
+        for triangle in &surface_mesh.triangles {
+            let triangle = triangle.to_surface_triangle();
+
+            for point in triangle.points {
+                let [u, v] = point.coords.components;
+                assert!(u <= Scalar::ONE || v <= Scalar::ONE);
+            }
+
+            area += triangle.area();
+        }
+        assert_abs_diff_eq!(area.into_f64(), 3., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn triangles_face_away_from_cylinder_axis() {
+        let surface = cylinder_surface();
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([2. * PI, 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.01);
+
+        for triangle in &surface_mesh.triangles {
+            let triangle = triangle.to_global_triangle();
+
+            // The cylinder's axis is the z-axis, so this points away from it.
+            let [x, y, _] = triangle.center().coords.components;
+            let outward = Vector::from([x, y, Scalar::ZERO]);
+
+            assert!(triangle.normal().dot(&outward) > Scalar::ZERO);
+        }
+    }
+
+    #[test]
+    fn finer_tolerance_subdivides_interior_boundary_edges() {
+        let surface = cylinder_surface();
+
+        // An L-shape, with its reentrant corner at `[1., 1.]`. The edge from
+        // `[2., 1.]` to `[1., 1.]` runs along the curved direction of the
+        // cylinder, through the interior of the polygon's bounding box. Its
+        // length is `1.`, as the cylinder has a radius of `1.`.
+        let polygon =
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]]
+                .map(Point::from);
+
+        let num_points_on_edge = |tolerance: f64| {
+            let surface_mesh =
+                SurfaceMesh::from_polygon(&surface, &polygon, tolerance);
+
+            surface_mesh
+                .boundary_edges()
+                .into_iter()
+                .flatten()
+                .map(|point| point.point_surface)
+                .filter(|point| point.v == Scalar::ONE)
+                .filter(|point| {
+                    point.u > Scalar::ONE && point.u < Scalar::from(2.)
+                })
+                .collect::<BTreeSet<_>>()
+                .len()
+        };
+
+        let coarse = num_points_on_edge(0.01);
+        let fine = num_points_on_edge(0.0001);
+
+        assert!(coarse > 0);
+        assert!(fine > coarse);
+    }
+
+    #[test]
+    fn triangle_order_is_deterministic() {
+        let surface = cylinder_surface();
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([PI, 1.]),
+        };
+
+        let a = SurfaceMesh::new(&surface, &boundary, 0.01).triangles;
+        let b = SurfaceMesh::new(&surface, &boundary, 0.01).triangles;
+        assert_eq!(a, b);
+
+        let keys = a
+            .iter()
+            .map(|triangle| {
+                let mut points = triangle.points;
+                points.sort();
+                points
+            })
+            .collect::<Vec<_>>();
+        assert!(keys.is_sorted());
+    }
+
+    #[test]
+    fn total_area() {
+        let surface = plane_surface(0.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        assert_eq!(surface_mesh.triangle_count(), 2);
+        assert_abs_diff_eq!(
+            surface_mesh.total_area().into_f64(),
+            1.,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn centroid() {
+        // Lots of points in one corner, none anywhere else.
+        let dense_corner = (1..10)
+            .flat_map(|i| (1..10).map(move |j| (i, j)))
+            .map(|(i, j)| Point::from([f64::from(i) / 50., f64::from(j) / 50.]))
+            .collect();
+
+        let surface = plane_with_curvature_points(dense_corner);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        assert_abs_diff_eq!(
+            surface_mesh.centroid().unwrap(),
+            Point::from([0.5, 0.5, 0.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let points = surface_mesh
+            .triangles
+            .iter()
+            .flat_map(|triangle| triangle.points)
+            .collect::<BTreeSet<_>>();
+        let point_average = points
+            .iter()
+            .map(|point| point.point_global.coords)
+            .fold(Vector::from([0., 0., 0.]), |sum, coords| sum + coords)
+            / points.len() as f64;
+
+        assert!(point_average.x < Scalar::from(0.25));
+        assert!(point_average.y < Scalar::from(0.25));
+    }
+
+    #[test]
+    fn centroid_of_empty_mesh() {
+        let surface_mesh = SurfaceMesh {
+            points: Vec::new(),
+            triangles: Vec::new(),
+            bvh: None,
+        };
+
+        assert_eq!(surface_mesh.centroid(), None);
+    }
+
+    #[test]
+    fn distortion() {
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([PI, 1.]),
+        };
+
+        let planar = SurfaceMesh::new(&plane_surface(0.), &boundary, 0.01);
+        for ratio in planar.distortion() {
+            assert_abs_diff_eq!(
+                ratio,
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+
+        // The triangles are flat, so they're a bit smaller than the curved
+        // patch of the cylinder that they approximate.
+        let curved = SurfaceMesh::new(&cylinder_surface(), &boundary, 0.01);
+        for ratio in curved.distortion() {
+            assert!(ratio < Scalar::from(1. - 1e-6), "{ratio:?}");
+            assert!(ratio > Scalar::from(0.9), "{ratio:?}");
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let surface = plane_surface(0.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let mut surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        assert!(surface_mesh.validate().is_ok());
+
+        let disconnected = [[2., 0.], [3., 0.], [3., 1.]].map(|point| {
+            TriangulationPoint::from_surface_point(
+                Point::from(point),
+                surface.geometry.as_ref(),
+            )
+        });
+        surface_mesh.triangles.push(MeshTriangle {
+            points: disconnected,
+        });
+
+        assert!(matches!(
+            surface_mesh.validate(),
+            Err(MeshValidationError::NotADisk {
+                euler_characteristic: 2,
+            }),
+        ));
+
+        let collinear = [[0., 0.], [0.5, 0.], [1., 0.]].map(|point| {
+            TriangulationPoint::from_surface_point(
+                Point::from(point),
+                surface.geometry.as_ref(),
+            )
+        });
+        surface_mesh
+            .triangles
+            .push(MeshTriangle { points: collinear });
+
+        assert!(matches!(
+            surface_mesh.validate(),
+            Err(MeshValidationError::DegenerateTriangle { index: 3, .. }),
+        ));
+    }
+
+    #[test]
+    fn boundary_edges() {
+        let surface = plane_surface(0.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        assert_eq!(surface_mesh.triangle_count(), 2);
+
+        let boundary_edges = surface_mesh.boundary_edges();
+        assert_eq!(boundary_edges.len(), 4);
+
+        // The quad's diagonal is shared between both triangles.
+        for [a, b] in boundary_edges {
+            let [a, b] = [a, b].map(|point| point.point_surface);
+            assert!(a.u == b.u || a.v == b.v);
+        }
+    }
+
+    #[test]
+    fn euler_characteristic() {
+        let surface = plane_surface(0.);
+        let point = |u: f64, v: f64| {
+            TriangulationPoint::from_surface_point(
+                Point::from([u, v]),
+                surface.geometry.as_ref(),
+            )
+        };
+
+        // A 3x3 grid of quads, each made up of two triangles, optionally
+        // leaving out the center quad.
+        let grid = |with_hole: bool| {
+            let mut triangles = Vec::new();
+
+            for u in 0..3 {
+                for v in 0..3 {
+                    if with_hole && [u, v] == [1, 1] {
+                        continue;
+                    }
+
+                    let [u, v] = [u, v].map(f64::from);
+                    let [a, b, c, d] = [
+                        point(u, v),
+                        point(u + 1., v),
+                        point(u + 1., v + 1.),
+                        point(u, v + 1.),
+                    ];
+
+                    triangles.push(MeshTriangle { points: [a, b, c] });
+                    triangles.push(MeshTriangle { points: [a, c, d] });
+                }
+            }
+
+            SurfaceMesh {
+                points: Vec::new(),
+                triangles,
+                bvh: None,
+            }
+        };
+
+        assert_eq!(grid(false).euler_characteristic(), 1);
+        assert_eq!(grid(true).euler_characteristic(), 0);
+    }
+
+    #[test]
+    fn refine_by_curvature() {
+        // A plane for negative `u`, that continues into a half-cylinder for
+        // positive `u`.
+        let surface = Surface {
+            geometry: Box::new(TestSurface::new(|point| {
+                let [u, v] = point.coords.components;
+
+                if u <= Scalar::ZERO {
+                    Point::from([u, v, Scalar::ZERO])
+                } else {
+                    Point::from([u.sin(), v, Scalar::ONE - u.cos()])
+                }
+            })),
+        };
+        let boundary = Aabb {
+            min: Point::from([-PI, 0.]),
+            max: Point::from([PI, 1.]),
+        };
+
+        let mut surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.5);
+        let num_triangles_before = surface_mesh.triangle_count();
+
+        let max_error = Scalar::from(0.001);
+        surface_mesh.refine_by_curvature(&surface, max_error);
+
+        assert!(surface_mesh.triangle_count() > num_triangles_before);
+        assert!(surface_mesh.validate().is_ok());
+
+        for triangle in &surface_mesh.triangles {
+            let center = triangle.to_surface_triangle().center();
+            let deviation = surface
+                .geometry
+                .point_from_local(center)
+                .distance_to(&triangle.to_global_triangle().center());
+
+            assert!(deviation <= max_error);
+        }
+
+        let [num_flat, num_curved] = [false, true].map(|curved| {
+            surface_mesh
+                .triangles
+                .iter()
+                .filter(|triangle| {
+                    let center = triangle.to_surface_triangle().center();
+                    (center.u > Scalar::ZERO) == curved
+                })
+                .count()
+        });
+        assert!(num_curved > num_flat * 4);
+
+        // A T-junction would leave an edge in the interior of the mesh that
+        // only belongs to one triangle.
+        for [a, b] in surface_mesh.boundary_edges() {
+            let [a, b] = [a, b].map(|point| point.point_surface);
+            assert!(
+                (a.u == b.u && a.u.abs() == Scalar::PI)
+                    || (a.v == b.v
+                        && (a.v == Scalar::ZERO || a.v == Scalar::ONE))
+            );
+        }
+        assert_eq!(surface_mesh.euler_characteristic(), 1);
+    }
+
+    #[test]
+    fn offset() {
+        let surface = plane_with_curvature_points(vec![
+            Point::from([0.25, 0.5]),
+            Point::from([0.5, 0.25]),
+            Point::from([0.75, 0.75]),
+        ]);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        let offset = surface_mesh.offset(0.5);
+
+        assert_eq!(offset.triangle_count(), surface_mesh.triangle_count());
+
+        for (original, offset) in
+            surface_mesh.triangles.iter().zip(&offset.triangles)
+        {
+            for (original, offset) in
+                original.points.into_iter().zip(offset.points)
+            {
+                assert_eq!(offset.point_surface, original.point_surface);
+                assert_eq!(
+                    offset.point_global - original.point_global,
+                    Vector::from([0., 0., 0.5]),
+                );
+            }
+        }
+
+        assert_eq!(offset.total_area(), surface_mesh.total_area());
+    }
+
+    #[test]
+    fn offset_without_normal() {
+        let point = |[u, v]: [f64; 2], global: [f64; 3]| TriangulationPoint {
+            point_surface: Point::from([u, v]),
+            point_global: Point::from(global),
+        };
+
+        // Two triangles that are folded onto each other along the edge from
+        // `a` to `b`. Their normals cancel out at `a` and `b`.
+        let a = point([0., 0.], [0., 0., 0.]);
+        let b = point([1., 0.], [1., 0., 0.]);
+        let c = point([0., 1.], [0., 1., 0.]);
+        let d = point([0., -1.], [0., 1., 0.]);
+
+        let surface_mesh = SurfaceMesh {
+            points: Vec::new(),
+            triangles: vec![
+                MeshTriangle { points: [a, b, c] },
+                MeshTriangle { points: [b, a, d] },
+            ],
+            bvh: None,
+        };
+
+        let offset = surface_mesh.offset(0.5);
+
+        let [oa, ob, oc] = offset.triangles[0].points;
+        assert_eq!([oa, ob], [a, b]);
+        assert_eq!(
+            oc.point_global - c.point_global,
+            Vector::from([0., 0., 0.5]),
+        );
+    }
+
+    #[test]
+    fn weld() {
+        let [a, b] = [0., 1.].map(|u| Aabb {
+            min: Point::from([u, 0.]),
+            max: Point::from([u + 1., 1.]),
+        });
+
+        // The other mesh is slightly offset, so its boundary points don't
+        // coincide exactly.
+        let mut surface_mesh = SurfaceMesh::new(&plane_surface(0.), &a, 0.001);
+        let other = SurfaceMesh::new(&plane_surface(1e-6), &b, 0.001);
+
+        let is_seam = |[a, b]: [TriangulationPoint; 2]| {
+            [a, b].map(|point| point.point_surface.u)
+                == [1., 1.].map(Scalar::from)
+        };
+        assert_eq!(
+            surface_mesh
+                .boundary_edges()
+                .into_iter()
+                .filter(|&edge| is_seam(edge))
+                .count(),
+            1,
+        );
+
+        surface_mesh.weld(&other, 0.001);
+
+        assert_eq!(surface_mesh.triangle_count(), 4);
+        assert_eq!(surface_mesh.boundary_edges().len(), 6);
+        assert!(!surface_mesh.boundary_edges().into_iter().any(is_seam));
+    }
+
+    #[test]
+    fn smooth() {
+        // A regular grid of points, each pushed off into a different
+        // direction.
+        let jagged = (1..5)
+            .flat_map(|i| (1..5).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let jitter = if (i + j) % 2 == 0 { 0.08 } else { -0.08 };
+                Point::from([
+                    f64::from(i) / 5. + jitter,
+                    f64::from(j) / 5. - jitter,
+                ])
+            })
+            .collect();
+
+        let surface = plane_with_curvature_points(jagged);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let mut surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        let average_aspect_ratio = |surface_mesh: &SurfaceMesh| {
+            surface_mesh
+                .triangles
+                .iter()
+                .map(|triangle| triangle.to_surface_triangle().aspect_ratio())
+                .fold(Scalar::ZERO, |sum, aspect_ratio| sum + aspect_ratio)
+                / surface_mesh.triangle_count() as f64
+        };
+
+        let boundary_edges_before = surface_mesh.boundary_edges();
+        let aspect_ratio_before = average_aspect_ratio(&surface_mesh);
+
+        surface_mesh.smooth(&surface, 10);
+
+        assert_eq!(surface_mesh.boundary_edges(), boundary_edges_before);
+        assert!(average_aspect_ratio(&surface_mesh) < aspect_ratio_before);
+    }
+
+    #[test]
+    fn simplify() {
+        let grid = (1..10)
+            .flat_map(|i| (1..10).map(move |j| (i, j)))
+            .map(|(i, j)| Point::from([f64::from(i) / 10., f64::from(j) / 10.]))
+            .collect();
+
+        let surface = plane_with_curvature_points(grid);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let mut surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        assert!(surface_mesh.triangle_count() > 100);
+
+        surface_mesh.simplify(&surface, 20);
+
+        assert_eq!(surface_mesh.triangle_count(), 20);
+        assert_abs_diff_eq!(
+            surface_mesh.total_area().into_f64(),
+            1.,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn nearest_point() {
+        let surface = plane_surface(1.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([2., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        let nearest = surface_mesh.nearest_point(Point::from([1.9, 0.1, 1.2]));
+        assert_eq!(nearest.point_surface, Point::from([2., 0.]));
+        assert_eq!(nearest.point_global, Point::from([2., 0., 1.]));
+    }
+
+    #[test]
+    fn cast_ray() {
+        let surface = plane_surface(1.);
+        let boundary = Aabb {
+            min: Point::from([-1., -1.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        // Aim at the center of the square, which is on the edge between its
+        // two triangles.
+        let origin = Point::from([0., 0., 3.]);
+        let dir = Vector::from([0., 0., -1.]);
+
+        let (index, toi, barycentric_coords) =
+            surface_mesh.cast_ray(origin, dir).unwrap();
+        assert_eq!(toi, Scalar::from(2.));
+
+        let triangle = surface_mesh.triangles[index].to_global_triangle();
+        assert_abs_diff_eq!(
+            triangle.point_from_barycentric_coords(barycentric_coords),
+            Point::from([0., 0., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        let miss = surface_mesh.cast_ray(origin, Vector::from([0., 0., 1.]));
+        assert!(miss.is_none());
+    }
+
+    #[test]
+    fn contains_point() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]].map(
+                |point_global| TriangulationPoint {
+                    point_surface: Point::from([0., 0.]),
+                    point_global: Point::from(point_global),
+                },
+            );
+
+        let tetrahedron = SurfaceMesh {
+            points: Vec::new(),
+            triangles: [[a, c, b], [a, b, d], [a, d, c], [b, c, d]]
+                .map(|points| MeshTriangle { points })
+                .into(),
+            bvh: None,
+        };
+
+        for inside in [[0.1, 0.1, 0.1], [0.25, 0.25, 0.25], [0.7, 0.1, 0.1]] {
+            assert_eq!(
+                tetrahedron.contains_point(Point::from(inside)),
+                Some(true)
+            );
+        }
+        for outside in [[-0.1, 0.1, 0.1], [0.5, 0.5, 0.5], [2., 2., 2.]] {
+            assert_eq!(
+                tetrahedron.contains_point(Point::from(outside)),
+                Some(false)
+            );
+        }
+
+        // A ray in the first direction that is tried passes right through the
+        // vertex at `d`.
+        let outside = d.point_global - Vector::from([1., 0.3, 0.7]);
+        assert_eq!(tetrahedron.contains_point(outside), Some(false));
+
+        // From a vertex, every ray starts on an edge of the mesh.
+        assert_eq!(tetrahedron.contains_point(d.point_global), None);
+
+        // Degenerate triangles are ignored. This one is on the path of the
+        // first ray that is cast.
+        let inside = Point::from([0.1, 0.1, 0.1]);
+        let degenerate = [0.5, 1., 1.5].map(|t| TriangulationPoint {
+            point_surface: Point::from([0., 0.]),
+            point_global: inside + Vector::from([1., 0.3, 0.7]) * t,
+        });
+
+        let mut with_degenerate_triangle = tetrahedron;
+        with_degenerate_triangle
+            .triangles
+            .push(MeshTriangle { points: degenerate });
+        assert_eq!(with_degenerate_triangle.contains_point(inside), Some(true));
+    }
+
+    #[test]
+    fn bvh() {
+        let grid = (1..51)
+            .flat_map(|i| (1..51).map(move |j| (i, j)))
+            .map(|(i, j)| Point::from([f64::from(i) / 51., f64::from(j) / 51.]))
+            .collect();
+
+        let surface = plane_with_curvature_points(grid);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let brute_force = SurfaceMesh::new(&surface, &boundary, 0.001);
+        assert!(brute_force.triangle_count() > 5000);
+
+        let mut accelerated = SurfaceMesh::new(&surface, &boundary, 0.001);
+        accelerated.build_bvh();
+
+        for i in 0..20 {
+            let t = f64::from(i) / 20.;
+
+            let target = Point::from([t, 1. - t * t, 0.1]);
+            assert_eq!(
+                accelerated.nearest_point(target),
+                brute_force.nearest_point(target),
+            );
+
+            let point = Point::from([t, 1. - t * t, 0.]);
+            assert_eq!(
+                accelerated.project_point(point, Tolerance::from(0.001)),
+                brute_force.project_point(point, Tolerance::from(0.001)),
+            );
+
+            let origin = Point::from([t, 0.5, 1.]);
+            let dir = Vector::from([0.1, t - 0.5, -1.]);
+            assert_eq!(
+                accelerated.cast_ray(origin, dir),
+                brute_force.cast_ray(origin, dir),
+            );
+        }
+
+        let miss = accelerated
+            .cast_ray(Point::from([2., 2., 1.]), Vector::from([0., 0., -1.]));
+        assert!(miss.is_none());
+    }
+
+    #[test]
+    fn to_triangle_strips() {
+        let grid = grid_points(
+            &Aabb {
+                min: Point::from([0.05, 0.05]),
+                max: Point::from([0.95, 0.95]),
+            },
+            10,
+            10,
+        );
+        let surface = plane_with_curvature_points(grid);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        let vertices = surface_mesh.vertices();
+
+        let strips = surface_mesh.to_triangle_strips();
+        assert!(
+            strips.len() * 5 < surface_mesh.triangle_count(),
+            "{} strips for {} triangles",
+            strips.len(),
+            surface_mesh.triangle_count(),
+        );
+
+        // Every triangle is part of exactly one strip, with the same winding.
+        let mut triangles_from_strips = Vec::new();
+        for strip in strips {
+            for (i, window) in strip.windows(3).enumerate() {
+                let [mut a, mut b, c] = [window[0], window[1], window[2]]
+                    .map(|index| vertices[index as usize]);
+                if i % 2 == 1 {
+                    std::mem::swap(&mut a, &mut b);
+                }
+
+                triangles_from_strips.push(normalize([a, b, c]));
+            }
+        }
+        triangles_from_strips.sort();
+
+        let mut triangles = surface_mesh
+            .triangles
+            .iter()
+            .map(|triangle| normalize(triangle.points))
+            .collect::<Vec<_>>();
+        triangles.sort();
+
+        assert_eq!(triangles_from_strips, triangles);
+
+        /// # Rotate the points, so the smallest one comes first
+        ///
+        /// Unlike sorting them, this preserves the winding.
+        fn normalize(
+            mut points: [TriangulationPoint; 3],
+        ) -> [TriangulationPoint; 3] {
+            let min = (0..3).min_by_key(|&i| points[i]).unwrap();
+            points.rotate_left(min);
+            points
+        }
+    }
+
+    #[test]
+    fn write_obj() {
+        let surface = plane_surface(0.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+
+        let mut obj = Vec::new();
+        surface_mesh.write_obj(&mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+
+        let mut num_vertices = 0;
+        let mut faces = Vec::new();
+        for line in obj.lines() {
+            let mut parts = line.split_whitespace();
+
+            match parts.next() {
+                Some("v") => {
+                    num_vertices += 1;
+                }
+                Some("f") => {
+                    let face = parts
+                        .map(|index| index.parse::<usize>().unwrap())
+                        .collect::<Vec<_>>();
+                    faces.push(face);
+                }
+                _ => panic!("Unexpected line in OBJ output: `{line}`"),
+            }
+        }
+
+        assert_eq!(num_vertices, 4);
+        assert_eq!(faces.len(), 2);
+        for index in faces.into_iter().flatten() {
+            assert!((1..=num_vertices).contains(&index));
+        }
+    }
+
+    #[test]
+    fn to_tri_mesh() {
+        let surface = plane_surface(1.);
+        let boundary = Aabb {
+            min: Point::from([-1., -1.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let surface_mesh = SurfaceMesh::new(&surface, &boundary, 0.001);
+        let tri_mesh = surface_mesh.to_tri_mesh();
+
+        assert_eq!(tri_mesh.triangles.len(), surface_mesh.triangle_count());
+        for triangle in tri_mesh.all_triangles() {
+            for point in triangle.points {
+                assert_eq!(point.z, Scalar::ONE);
+            }
+        }
+    }
+
+    /// # Create a plane whose approximation yields the provided points
+    ///
+    /// The plane is the xy-plane, and the points are returned as the
+    /// approximation of its curvature.
+    fn plane_with_curvature_points(curvature: Vec<Point<2>>) -> Surface {
+        Surface {
+            geometry: Box::new(TestSurface::xy_plane().with_approximation(
+                move |_| SurfaceApprox {
+                    curvature: curvature.clone(),
+                    boundary: Vec::new(),
+                },
+            )),
+        }
+    }
+}
@@ -24,6 +24,13 @@ impl Circle {
         self.a * cos + self.b * sin - self.a
     }
 
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<3> {
+        let angle = point.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.b * cos - self.a * sin
+    }
+
     pub fn project_vector(&self, vector: impl Into<Vector<3>>) -> Point<1> {
         let vector = self.a + vector.into();
 
@@ -50,6 +57,10 @@ impl CurveGeometry for Circle {
         self.vector_from_local_point(point)
     }
 
+    fn tangent_at(&self, point: Point<1>) -> Vector<3> {
+        self.tangent_at(point)
+    }
+
     fn project_vector(&self, vector: Vector<3>) -> Point<1> {
         self.project_vector(vector)
     }
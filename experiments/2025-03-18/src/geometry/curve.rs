@@ -55,6 +55,10 @@ impl AnchoredCurve {
         self.origin + self.floating.vector_from_local_point(point.into())
     }
 
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<3> {
+        self.floating.tangent_at(point)
+    }
+
     pub fn project_point(&self, point: Point<3>) -> Point<1> {
         self.floating.inner.project_vector(point - self.origin)
     }
@@ -99,6 +103,10 @@ impl FloatingCurve {
         self.inner.vector_from_local_point(point.into())
     }
 
+    pub fn tangent_at(&self, point: impl Into<Point<1>>) -> Vector<3> {
+        self.inner.tangent_at(point.into())
+    }
+
     pub fn flip(&self) -> Self {
         Self {
             inner: self.inner.flip(),
@@ -130,6 +138,13 @@ impl Clone for FloatingCurve {
 pub trait CurveGeometry: fmt::Debug {
     fn clone_curve_geometry(&self) -> Box<dyn CurveGeometry>;
     fn vector_from_local_point(&self, point: Point<1>) -> Vector<3>;
+
+    /// # Compute the derivative of the curve at the provided point
+    ///
+    /// This is the derivative of [`CurveGeometry::vector_from_local_point`]
+    /// with respect to the curve coordinate. It's not normalized.
+    fn tangent_at(&self, point: Point<1>) -> Vector<3>;
+
     fn project_vector(&self, vector: Vector<3>) -> Point<1>;
     fn flip(&self) -> Box<dyn CurveGeometry>;
 
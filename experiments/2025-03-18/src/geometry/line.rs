@@ -32,6 +32,10 @@ impl CurveGeometry for Line {
         self.vector_from_local_point(point)
     }
 
+    fn tangent_at(&self, _: Point<1>) -> Vector<3> {
+        self.direction
+    }
+
     fn project_vector(&self, vector: Vector<3>) -> Point<1> {
         self.project_vector(vector)
     }
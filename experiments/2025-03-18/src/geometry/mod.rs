@@ -10,6 +10,6 @@ pub use self::{
     curve::{AnchoredCurve, FloatingCurve},
     line::Line,
     sketch::Sketch,
-    surface::SurfaceGeometry,
+    surface::{SurfaceApprox, SurfaceGeometry},
     swept_curve::SweptCurve,
 };
@@ -1,10 +1,39 @@
 use std::fmt;
 
 use fj_interop::Tolerance;
-use fj_math::{Aabb, Point, Vector};
+use fj_math::{Aabb, Point, Scalar, Vector};
 
 pub trait SurfaceGeometry: fmt::Debug {
     fn point_from_local(&self, point: Point<2>) -> Point<3>;
+
+    /// # Compute the normal of the surface at the provided point
+    ///
+    /// The normal is the normalized cross product of the derivatives of
+    /// [`SurfaceGeometry::point_from_local`] along the u-axis and the v-axis,
+    /// in that order.
+    ///
+    /// By default, those derivatives are estimated using finite differences.
+    /// Implementations that can compute them in closed form should override
+    /// this.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the surface is degenerate at the provided point, meaning the
+    /// derivatives are parallel, or one of them is zero.
+    fn normal_at(&self, uv: Point<2>) -> Vector<3> {
+        let step = Scalar::from(1e-6);
+
+        let derivative = |offset: Vector<2>| {
+            (self.point_from_local(uv + offset)
+                - self.point_from_local(uv - offset))
+                / (step * 2.)
+        };
+        let du = derivative(Vector::from([step, Scalar::ZERO]));
+        let dv = derivative(Vector::from([Scalar::ZERO, step]));
+
+        du.cross(&dv).normalize()
+    }
+
     fn flip(&self) -> Box<dyn SurfaceGeometry>;
     fn translate(&self, offset: Vector<3>) -> Box<dyn SurfaceGeometry>;
     fn approximate(
@@ -2,7 +2,7 @@ use fj_interop::Tolerance;
 use fj_math::{Aabb, Point, Vector};
 use itertools::Itertools;
 
-use crate::geometry::{SurfaceGeometry, surface::SurfaceApprox};
+use crate::geometry::{SurfaceApprox, SurfaceGeometry};
 
 use super::{AnchoredCurve, Line, curve::FloatingCurve};
 
@@ -62,6 +62,14 @@ impl SweptCurve {
         self.u.point_from_local([u]) + self.v.vector_from_local_point([v])
     }
 
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Vector<3> {
+        let [u, v] = point.into().coords.components;
+        let du = self.u.tangent_at([u]);
+        let dv = self.v.tangent_at([v]);
+
+        du.cross(&dv).normalize()
+    }
+
     pub fn flip(&self) -> Self {
         Self {
             u: self.u.clone(),
@@ -82,6 +90,10 @@ impl SurfaceGeometry for SweptCurve {
         self.point_from_local(point)
     }
 
+    fn normal_at(&self, uv: Point<2>) -> Vector<3> {
+        self.normal_at(uv)
+    }
+
     fn flip(&self) -> Box<dyn SurfaceGeometry> {
         Box::new((*self).flip())
     }
@@ -154,3 +166,108 @@ impl SurfaceGeometry for SweptCurve {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use approx::assert_abs_diff_eq;
+    use fj_interop::Tolerance;
+    use fj_math::{Aabb, Point, Scalar, Vector};
+
+    use crate::{
+        geometry::SurfaceGeometry,
+        test_helpers::{TestSurface, cylinder, plane},
+    };
+
+    use super::SweptCurve;
+
+    #[test]
+    fn approximate_plane() {
+        let plane = plane(0.);
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([1., 1.]),
+        };
+
+        let approx = plane.approximate(&boundary, Tolerance::from(0.001));
+
+        assert!(approx.curvature.is_empty());
+        assert_eq!(approx.boundary.len(), 4);
+    }
+
+    #[test]
+    fn approximate_cylinder_within_tolerance() {
+        let cylinder = cylinder();
+        let boundary = Aabb {
+            min: Point::from([0., 0.]),
+            max: Point::from([2. * PI, 1.]),
+        };
+
+        for tolerance in [0.1, 0.01, 0.001] {
+            let tolerance = Tolerance::from(tolerance);
+            let approx = cylinder.approximate(&boundary, tolerance);
+
+            let mut points_along_u = approx
+                .boundary
+                .into_iter()
+                .filter(|point| point.v == Scalar::ZERO)
+                .collect::<Vec<_>>();
+            points_along_u.sort();
+
+            assert!(points_along_u.len() > 2);
+
+            for pair in points_along_u.windows(2) {
+                let [a, b] = [pair[0], pair[1]];
+
+                let chord_midpoint = cylinder
+                    .point_from_local(a)
+                    .lerp(&cylinder.point_from_local(b), 0.5);
+                let surface_point = cylinder.point_from_local(a.lerp(&b, 0.5));
+
+                assert!(
+                    surface_point.distance_to(&chord_midpoint)
+                        < tolerance.inner()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn normal_at_plane() {
+        let plane = SweptCurve::plane_from_coord_system(
+            [0., 0., 0.],
+            [[2., 0., 0.], [0., 3., 0.]],
+        );
+
+        for uv in [[0., 0.], [1., -2.], [-3., 0.5]] {
+            assert_eq!(plane.normal_at(uv), Vector::from([0., 0., 1.]));
+        }
+    }
+
+    #[test]
+    fn normal_at_cylinder() {
+        // Uses the default implementation of `normal_at`, which is based on
+        // finite differences. It should agree with the closed-form one.
+        let finite_differences = {
+            let cylinder = cylinder();
+            TestSurface::new(move |point| cylinder.point_from_local(point))
+        };
+
+        let cylinder = cylinder();
+
+        for i in 0..8 {
+            let u = f64::from(i) * PI / 4.;
+            let uv = Point::from([u, 0.5]);
+
+            let expected = Vector::from([u.cos(), u.sin(), 0.]);
+            assert_abs_diff_eq!(cylinder.normal_at(uv), expected);
+
+            assert_abs_diff_eq!(
+                finite_differences.normal_at(uv),
+                expected,
+                epsilon = Scalar::from(1e-6),
+            );
+        }
+    }
+}
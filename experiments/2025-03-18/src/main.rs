@@ -9,6 +9,9 @@ mod operations;
 mod topology;
 mod viewer;
 
+#[cfg(test)]
+mod test_helpers;
+
 use debug::DEBUG_WINDOW;
 
 fn main() -> anyhow::Result<()> {
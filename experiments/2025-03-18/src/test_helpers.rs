@@ -0,0 +1,129 @@
+//! # Fixtures that are shared between tests
+
+use std::fmt;
+
+use fj_interop::Tolerance;
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{
+        AnchoredCurve, Circle, FloatingCurve, Line, SurfaceApprox,
+        SurfaceGeometry, SweptCurve,
+    },
+    topology::surface::Surface,
+};
+
+/// # Create a plane that is parallel to the xy-plane, at the provided height
+///
+/// The u- and v-axes of the plane are the x- and y-axes.
+pub fn plane(z: f64) -> SweptCurve {
+    SweptCurve::plane_from_coord_system(
+        [0., 0., z],
+        [[1., 0., 0.], [0., 1., 0.]],
+    )
+}
+
+/// # Create a cylinder with radius 1, around the z-axis
+///
+/// The u-axis goes around the cylinder, starting at `[1, 0, 0]`. The v-axis is
+/// parallel to the z-axis.
+pub fn cylinder() -> SweptCurve {
+    SweptCurve {
+        u: AnchoredCurve::from_origin_and_curve(
+            Point::from([1., 0., 0.]),
+            Circle {
+                a: Vector::from([1., 0., 0.]),
+                b: Vector::from([0., 1., 0.]),
+            },
+        ),
+        v: FloatingCurve::new(Line {
+            direction: Vector::from([0., 0., 1.]),
+        }),
+    }
+}
+
+/// # Create a [`Surface`] from [`plane`]
+pub fn plane_surface(z: f64) -> Surface {
+    Surface {
+        geometry: Box::new(plane(z)),
+    }
+}
+
+/// # Create a [`Surface`] from [`cylinder`]
+pub fn cylinder_surface() -> Surface {
+    Surface {
+        geometry: Box::new(cylinder()),
+    }
+}
+
+/// # A surface geometry that is defined by closures
+///
+/// This is useful for tests that need a surface with specific behavior. Only
+/// [`SurfaceGeometry::point_from_local`] and [`SurfaceGeometry::approximate`]
+/// are supported. The other required methods panic.
+pub struct TestSurface {
+    point_from_local: Box<dyn Fn(Point<2>) -> Point<3>>,
+    approximate: Box<dyn Fn(&Aabb<2>) -> SurfaceApprox>,
+}
+
+impl TestSurface {
+    /// # Create a surface from the provided conversion to global points
+    ///
+    /// The approximation of the surface is empty, unless one is provided using
+    /// [`TestSurface::with_approximation`].
+    pub fn new(
+        point_from_local: impl Fn(Point<2>) -> Point<3> + 'static,
+    ) -> Self {
+        Self {
+            point_from_local: Box::new(point_from_local),
+            approximate: Box::new(|_| SurfaceApprox {
+                curvature: Vec::new(),
+                boundary: Vec::new(),
+            }),
+        }
+    }
+
+    /// # Create a surface that is the xy-plane
+    pub fn xy_plane() -> Self {
+        Self::new(|point| {
+            let [u, v] = point.coords.components;
+            Point::from([u, v, Scalar::ZERO])
+        })
+    }
+
+    /// # Replace the approximation of the surface
+    ///
+    /// The closure receives the boundary that is passed to
+    /// [`SurfaceGeometry::approximate`]. The tolerance is ignored.
+    pub fn with_approximation(
+        mut self,
+        approximate: impl Fn(&Aabb<2>) -> SurfaceApprox + 'static,
+    ) -> Self {
+        self.approximate = Box::new(approximate);
+        self
+    }
+}
+
+impl SurfaceGeometry for TestSurface {
+    fn point_from_local(&self, point: Point<2>) -> Point<3> {
+        (self.point_from_local)(point)
+    }
+
+    fn flip(&self) -> Box<dyn SurfaceGeometry> {
+        unreachable!("Not supported by `TestSurface`.");
+    }
+
+    fn translate(&self, _: Vector<3>) -> Box<dyn SurfaceGeometry> {
+        unreachable!("Not supported by `TestSurface`.");
+    }
+
+    fn approximate(&self, boundary: &Aabb<2>, _: Tolerance) -> SurfaceApprox {
+        (self.approximate)(boundary)
+    }
+}
+
+impl fmt::Debug for TestSurface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TestSurface").finish_non_exhaustive()
+    }
+}
@@ -265,7 +265,8 @@ pub enum Displayable {
 impl Displayable {
     pub fn face(points: Vec<Point<2>>) -> Self {
         let aabb =
-            Aabb::<3>::from_points(points.iter().map(|point| point.to_xyz()));
+            Aabb::<3>::from_points(points.iter().map(|point| point.to_xyz()))
+                .unwrap_or_default();
         Self::Face { points, aabb }
     }
 